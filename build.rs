@@ -16,8 +16,8 @@ fn main()
     let head = Path::new(".git/logs/HEAD");
     if head.exists() {
         if let Ok(data) = fs::read_to_string(head) {
-            if let Some(lastline) = data.lines().rev().next() {
-                if let Some(hash) = lastline.split_ascii_whitespace().skip(1).next() {
+            if let Some(lastline) = data.lines().next_back() {
+                if let Some(hash) = lastline.split_ascii_whitespace().nth(1) {
                     git_hash = hash.to_string();
                 }
             }
@@ -13,6 +13,7 @@ fn main() {
     println!("Work area:      {}x{}", wm.work_area.0, wm.work_area.1);
     println!("Screen Size:    {}x{}", wm.screen_size.0, wm.screen_size.1);
     println!("Desktops:       {}", wm.desktops);
+    println!("Windows:        {} managed, {} total", wm.num_managed_windows, wm.num_all_windows);
     println!("Active Window:  {}", win.id);
     println!();
 
@@ -31,7 +32,7 @@ fn main() {
     let mut atoms = wm.supported.iter().collect::<Vec<_>>();
     atoms.sort_by(|a, b| a.1.cmp(b.1));
     for atom in atoms.iter() {
-        table.add_row(Row::new(vec![Cell::new(&atom.1), Cell::new(&atom.0.to_string())]));
+        table.add_row(Row::new(vec![Cell::new(atom.1), Cell::new(&atom.0.to_string())]));
     }
     table.printstd();
 }
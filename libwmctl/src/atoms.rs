@@ -7,6 +7,7 @@ use x11rb::atom_manager;
 atom_manager! {
     pub AtomCollection: AtomCollectionCookie {
         // GNOME custom hints
+        _GTK_APPLICATION_ID,
         _GTK_FRAME_EXTENTS,
 
         // Standard Extended Window Manager Hints
@@ -23,6 +24,7 @@ atom_manager! {
         _NET_MOVERESIZE_WINDOW,
         _NET_NUMBER_OF_DESKTOPS,
         _NET_REQUEST_FRAME_EXTENTS,
+        _NET_RESTACK_WINDOW,
         _NET_SHOWING_DESKTOP,
         _NET_SUPPORTED,
         _NET_SUPPORTING_WM_CHECK,
@@ -94,5 +96,11 @@ atom_manager! {
         _NET_WM_WINDOW_TYPE_TOOLTIP,
         _NET_WM_WINDOW_TYPE_UTILITY,
         UTF8_STRING,
+
+        // ICCCM hints
+        WM_CHANGE_STATE,
+        WM_PROTOCOLS,
+        WM_STATE,
+        WM_WINDOW_ROLE,
     }
 }
@@ -0,0 +1,141 @@
+// ## References
+// * https://gitlab.freedesktop.org/xorg/lib/libxau/-/blob/master/AuDispose.c
+// * https://github.com/psychon/x11rb/blob/master/x11rb-protocol/src/xauth.rs
+//
+// ## Details
+// * An Xauthority file is a sequence of entries each of which is made up of a big endian u16
+//   family followed by the address, display number, auth name and auth data as byte strings.
+// * Each byte string is stored as a big endian u16 length followed by that many bytes.
+// * x11rb only reads the file pointed to by $XAUTHORITY or ~/.Xauthority so this allows for an
+//   explicit file to be used instead e.g. when running as a different user than the session's.
+use std::{
+    fs::File,
+    io::{self, BufReader, ErrorKind, Read},
+    path::Path,
+};
+use x11rb::reexports::x11rb_protocol::xauth::Family;
+
+const MIT_MAGIC_COOKIE_1: &[u8] = b"MIT-MAGIC-COOKIE-1";
+
+/// Authentication name and data to pass on to the X11 connection setup
+pub(crate) type AuthInfo = (Vec<u8>, Vec<u8>);
+
+/// A single entry of an Xauthority file
+struct AuthEntry {
+    family: Family,   // protocol family the entry applies to
+    address: Vec<u8>, // address of the peer in a family specific format
+    number: Vec<u8>,  // display number
+    name: Vec<u8>,    // name of the authentication method
+    data: Vec<u8>,    // data for the authentication method
+}
+
+/// Read the authentication information for the given display from the given Xauthority file
+///
+/// ### Arguments
+/// * `path` - path to the Xauthority file to read
+/// * `family` - protocol family used to connect to the X11 server
+/// * `address` - address of the X11 server in a family specific format
+/// * `display` - display number of the X11 server
+pub(crate) fn read_auth(
+    path: &Path, family: Family, address: &[u8], display: u16,
+) -> io::Result<Option<AuthInfo>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    find_auth(&mut reader, family, address, display)
+}
+
+/// Find the first MIT-MAGIC-COOKIE-1 entry matching the given family, address and display
+fn find_auth<R: Read>(read: &mut R, family: Family, address: &[u8], display: u16) -> io::Result<Option<AuthInfo>> {
+    let display = display.to_string();
+    while let Some(entry) = read_entry(read)? {
+        let address_matches = if family == Family::WILD || entry.family == Family::WILD {
+            true
+        } else {
+            entry.family == family && entry.address == address
+        };
+        let display_matches = entry.number.is_empty() || entry.number == display.as_bytes();
+        if address_matches && display_matches && entry.name == MIT_MAGIC_COOKIE_1 {
+            return Ok(Some((entry.name, entry.data)));
+        }
+    }
+    Ok(None)
+}
+
+/// Read a single entry returning None at the end of the file
+fn read_entry<R: Read>(read: &mut R) -> io::Result<Option<AuthEntry>> {
+    let family = match read_u16(read) {
+        Ok(family) => Family::from(family),
+        Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    Ok(Some(AuthEntry {
+        family,
+        address: read_bytes(read)?,
+        number: read_bytes(read)?,
+        name: read_bytes(read)?,
+        data: read_bytes(read)?,
+    }))
+}
+
+/// Read a big endian u16
+fn read_u16<R: Read>(read: &mut R) -> io::Result<u16> {
+    let mut buf = [0; 2];
+    read.read_exact(&mut buf)?;
+    Ok(u16::from_be_bytes(buf))
+}
+
+/// Read a length prefixed byte string
+fn read_bytes<R: Read>(read: &mut R) -> io::Result<Vec<u8>> {
+    let mut buf = vec![0; read_u16(read)? as usize];
+    read.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    // Encode an entry the way it is stored in an Xauthority file
+    fn entry(family: u16, address: &[u8], number: &[u8], name: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut buf = family.to_be_bytes().to_vec();
+        for x in [address, number, name, data] {
+            buf.extend_from_slice(&(x.len() as u16).to_be_bytes());
+            buf.extend_from_slice(x);
+        }
+        buf
+    }
+
+    #[test]
+    fn test_find_auth() {
+        let mut data = entry(256, b"otherhost", b"0", MIT_MAGIC_COOKIE_1, &[1, 2]);
+        data.extend(entry(256, b"myhost", b"1", b"XDM-AUTHORIZATION-1", &[3, 4]));
+        data.extend(entry(256, b"myhost", b"1", MIT_MAGIC_COOKIE_1, &[5, 6]));
+
+        // Matching family, address and display
+        let auth = find_auth(&mut Cursor::new(&data), Family::LOCAL, b"myhost", 1).unwrap();
+        assert_eq!(auth, Some((MIT_MAGIC_COOKIE_1.to_vec(), vec![5, 6])));
+
+        // Wrong display number
+        let auth = find_auth(&mut Cursor::new(&data), Family::LOCAL, b"myhost", 2).unwrap();
+        assert_eq!(auth, None);
+
+        // Wrong family
+        let auth = find_auth(&mut Cursor::new(&data), Family::INTERNET, b"myhost", 1).unwrap();
+        assert_eq!(auth, None);
+    }
+
+    #[test]
+    fn test_find_auth_wildcards() {
+        // Wild family and empty display number match anything
+        let data = entry(65535, b"", b"", MIT_MAGIC_COOKIE_1, &[7, 8]);
+        let auth = find_auth(&mut Cursor::new(&data), Family::LOCAL, b"myhost", 3).unwrap();
+        assert_eq!(auth, Some((MIT_MAGIC_COOKIE_1.to_vec(), vec![7, 8])));
+    }
+
+    #[test]
+    fn test_find_auth_truncated() {
+        let data = entry(256, b"myhost", b"1", MIT_MAGIC_COOKIE_1, &[5, 6]);
+        let result = find_auth(&mut Cursor::new(&data[..data.len() - 1]), Family::LOCAL, b"myhost", 1);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{model::*, winmgr::WinMgr, WmCtlResult};
+
+/// WmConnection provides access to a window manager on a display other than the default one
+/// without affecting the shared connection used by the rest of `libwmctl`. Windows are referred to
+/// by id as the `Window` type always uses the shared connection.
+pub struct WmConnection {
+    wm: WinMgr,
+}
+
+impl WmConnection {
+    pub(crate) fn new(wm: WinMgr) -> Self {
+        Self { wm }
+    }
+
+    /// Get window manager informational properties
+    pub fn info(&self) -> WmCtlResult<Info> {
+        self.wm.info()
+    }
+
+    /// Get the active window id
+    pub fn active_window(&self) -> WmCtlResult<u32> {
+        self.wm.active_window()
+    }
+
+    /// Get the active desktop numbered from 1
+    pub fn active_desktop(&self) -> WmCtlResult<u32> {
+        self.wm.active_desktop()
+    }
+
+    /// Switch to the given desktop numbered from 1
+    ///
+    /// ### Arguments
+    /// * `desktop` - desktop to switch to
+    pub fn switch_desktop(&self, desktop: u32) -> WmCtlResult<()> {
+        self.wm.set_active_desktop(desktop)
+    }
+
+    /// Get the ids of the windows the window manager is managing
+    ///
+    /// ### Arguments
+    /// * `hidden` - when set to true will list all x11 windows not just those the window manager lists
+    pub fn windows(&self, hidden: bool) -> WmCtlResult<Vec<u32>> {
+        self.wm.windows(hidden)
+    }
+
+    /// Get the window's name
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to query
+    pub fn window_name(&self, id: u32) -> WmCtlResult<String> {
+        self.wm.window_name(id, false)
+    }
+
+    /// Get the window's class
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to query
+    pub fn window_class(&self, id: u32) -> WmCtlResult<String> {
+        self.wm.window_class(id)
+    }
+
+    /// Get the window's pid
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to query
+    pub fn window_pid(&self, id: u32) -> WmCtlResult<i32> {
+        self.wm.window_pid(id)
+    }
+
+    /// Get the window's desktop numbered from 1 or -1 for sticky windows
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to query
+    pub fn window_desktop(&self, id: u32) -> WmCtlResult<i32> {
+        self.wm.window_desktop(id)
+    }
+
+    /// Get the window's states
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to query
+    pub fn window_state(&self, id: u32) -> WmCtlResult<Vec<State>> {
+        self.wm.window_state(id)
+    }
+
+    /// Get the window's geometry
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to query
+    pub fn window_geometry(&self, id: u32) -> WmCtlResult<Geometry> {
+        Ok(Geometry::from(self.wm.window_geometry(id)?))
+    }
+
+    /// Focus the window and bring it to the front of the stacking order
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    pub fn focus(&self, id: u32) -> WmCtlResult<()> {
+        self.wm.focus_window(id)
+    }
+
+    /// Maximize the window
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    pub fn maximize(&self, id: u32) -> WmCtlResult<()> {
+        self.wm.maximize_window(id)
+    }
+
+    /// Unmaximize the window
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    pub fn unmaximize(&self, id: u32) -> WmCtlResult<()> {
+        self.wm.unmaximize_window(id)
+    }
+
+    /// Move and resize the window
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `x` - x coordinate to move the window to or None for no change
+    /// * `y` - y coordinate to move the window to or None for no change
+    /// * `w` - width to resize the window to or None for no change
+    /// * `h` - height to resize the window to or None for no change
+    pub fn move_resize(
+        &self, id: u32, x: Option<i32>, y: Option<i32>, w: Option<u32>, h: Option<u32>,
+    ) -> WmCtlResult<()> {
+        self.wm.move_resize_window(id, None, x, y, w, h)
+    }
+
+    /// Move the window to the given desktop numbered from 1 or `DESKTOP_STICKY`
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `desktop` - desktop to move the window to
+    pub fn move_to_desktop(&self, id: u32, desktop: u32) -> WmCtlResult<()> {
+        self.wm.move_window_to_desktop(id, desktop)
+    }
+}
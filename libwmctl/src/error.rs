@@ -7,8 +7,18 @@ pub type WmCtlResult<T> = std::result::Result<T, ErrorWrapper>;
 /// WmCtlError defines all the internal errors that `libwmctl` might return
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum WmCtlError {
+    ActiveWinNotFound,
+    AlreadyConnected,
+    CompositorNotFound,
     DesktopWinNotFound,
     InvalidAtom(String),
+    InvalidDesktop(u32),
+    InvalidGrid(String),
+    InvalidLayout(String),
+    InvalidMonitor(usize),
+    InvalidPattern(String),
+    InvalidSelector(String),
+    InvalidWinAction(u32),
     InvalidWinGravity(u32),
     InvalidWinPosition(String),
     InvalidWinShape(String),
@@ -16,16 +26,30 @@ pub enum WmCtlError {
     InvalidWinMap(u32),
     InvalidWinState(u32),
     InvalidWinType(u32),
+    InvalidStrut(String),
     PropertyNotFound(String),
     TaskbarNotFound,
     TaskbarReservationNotFound,
+    Timeout,
+    Unsupported(String),
+    WinNotFound(String),
 }
 impl std::error::Error for WmCtlError {}
 impl fmt::Display for WmCtlError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
+            WmCtlError::ActiveWinNotFound => write!(f, "active window was not found"),
+            WmCtlError::AlreadyConnected => write!(f, "connection was already established"),
+            WmCtlError::CompositorNotFound => write!(f, "compositing manager was not found"),
             WmCtlError::DesktopWinNotFound => write!(f, "desktop window was not found"),
             WmCtlError::InvalidAtom(ref err) => write!(f, "invalid atom was given: {}", err),
+            WmCtlError::InvalidDesktop(ref err) => write!(f, "invalid desktop was given: {}", err),
+            WmCtlError::InvalidGrid(ref err) => write!(f, "invalid grid was given: {}", err),
+            WmCtlError::InvalidLayout(ref err) => write!(f, "invalid layout was given: {}", err),
+            WmCtlError::InvalidMonitor(ref err) => write!(f, "invalid monitor was given: {}", err),
+            WmCtlError::InvalidPattern(ref err) => write!(f, "invalid pattern was given: {}", err),
+            WmCtlError::InvalidSelector(ref err) => write!(f, "invalid selector was given: {}", err),
+            WmCtlError::InvalidWinAction(ref err) => write!(f, "invalid action was given: {}", err),
             WmCtlError::InvalidWinGravity(ref err) => write!(f, "invalid gravity was given: {}", err),
             WmCtlError::InvalidWinPosition(ref err) => write!(f, "invalid position was given: {}", err),
             WmCtlError::InvalidWinShape(ref err) => write!(f, "invalid shape was given: {}", err),
@@ -33,9 +57,13 @@ impl fmt::Display for WmCtlError {
             WmCtlError::InvalidWinMap(ref err) => write!(f, "invalid map was given: {}", err),
             WmCtlError::InvalidWinState(ref err) => write!(f, "invalid state was given: {}", err),
             WmCtlError::InvalidWinType(ref err) => write!(f, "invalid type was given: {}", err),
+            WmCtlError::InvalidStrut(ref err) => write!(f, "invalid strut was given: {}", err),
             WmCtlError::PropertyNotFound(ref err) => write!(f, "property {} was not found", err),
             WmCtlError::TaskbarNotFound => write!(f, "taskbar not found"),
             WmCtlError::TaskbarReservationNotFound => write!(f, "taskbar reservation not found"),
+            WmCtlError::Timeout => write!(f, "operation timed out"),
+            WmCtlError::Unsupported(ref err) => write!(f, "{} is not supported by the window manager", err),
+            WmCtlError::WinNotFound(ref err) => write!(f, "window matching {} was not found", err),
         }
     }
 }
@@ -46,13 +74,15 @@ impl fmt::Display for WmCtlError {
 pub enum ErrorWrapper {
     WmCtl(WmCtlError),
 
-    // std::str::Utf8Error
+    // std errors
+    Io(std::io::Error),
     Utf8(std::str::Utf8Error),
 
     // x11rb errors
     Connect(x11rb::errors::ConnectError),
     Connection(x11rb::errors::ConnectionError),
     Reply(x11rb::errors::ReplyError),
+    ReplyOrId(x11rb::errors::ReplyOrIdError),
 }
 impl ErrorWrapper {
     /// Implemented directly on the `Error` type to reduce casting required
@@ -75,6 +105,26 @@ impl ErrorWrapper {
     pub fn source(&self) -> Option<&(dyn StdError + 'static)> {
         self.as_ref().source()
     }
+
+    /// Check if the error is likely transient and the operation worth retrying. This covers X11
+    /// errors caused by racing the window manager e.g. a window being destroyed or not yet mapped
+    /// as well as properties not yet set and changes not yet applied.
+    pub fn is_retryable(&self) -> bool {
+        use x11rb::errors::{ReplyError, ReplyOrIdError};
+        use x11rb::protocol::ErrorKind;
+        let x11 = match self {
+            ErrorWrapper::WmCtl(err) => {
+                return matches!(
+                    err,
+                    WmCtlError::ActiveWinNotFound | WmCtlError::PropertyNotFound(_) | WmCtlError::Timeout
+                )
+            },
+            ErrorWrapper::Reply(ReplyError::X11Error(err)) => err,
+            ErrorWrapper::ReplyOrId(ReplyOrIdError::X11Error(err)) => err,
+            _ => return false,
+        };
+        matches!(x11.error_kind, ErrorKind::Window | ErrorKind::Drawable | ErrorKind::Match)
+    }
 }
 impl StdError for ErrorWrapper {}
 
@@ -82,10 +132,12 @@ impl fmt::Display for ErrorWrapper {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ErrorWrapper::WmCtl(ref err) => write!(f, "{}", err),
+            ErrorWrapper::Io(ref err) => write!(f, "{}", err),
             ErrorWrapper::Utf8(ref err) => write!(f, "{}", err),
             ErrorWrapper::Connect(ref err) => write!(f, "{}", err),
             ErrorWrapper::Connection(ref err) => write!(f, "{}", err),
             ErrorWrapper::Reply(ref err) => write!(f, "{}", err),
+            ErrorWrapper::ReplyOrId(ref err) => write!(f, "{}", err),
         }
     }
 }
@@ -94,10 +146,12 @@ impl AsRef<dyn StdError> for ErrorWrapper {
     fn as_ref(&self) -> &(dyn StdError + 'static) {
         match *self {
             ErrorWrapper::WmCtl(ref err) => err,
+            ErrorWrapper::Io(ref err) => err,
             ErrorWrapper::Utf8(ref err) => err,
             ErrorWrapper::Connect(ref err) => err,
             ErrorWrapper::Connection(ref err) => err,
             ErrorWrapper::Reply(ref err) => err,
+            ErrorWrapper::ReplyOrId(ref err) => err,
         }
     }
 }
@@ -106,10 +160,12 @@ impl AsMut<dyn StdError> for ErrorWrapper {
     fn as_mut(&mut self) -> &mut (dyn StdError + 'static) {
         match *self {
             ErrorWrapper::WmCtl(ref mut err) => err,
+            ErrorWrapper::Io(ref mut err) => err,
             ErrorWrapper::Utf8(ref mut err) => err,
             ErrorWrapper::Connect(ref mut err) => err,
             ErrorWrapper::Connection(ref mut err) => err,
             ErrorWrapper::Reply(ref mut err) => err,
+            ErrorWrapper::ReplyOrId(ref mut err) => err,
         }
     }
 }
@@ -120,6 +176,12 @@ impl From<WmCtlError> for ErrorWrapper {
     }
 }
 
+impl From<std::io::Error> for ErrorWrapper {
+    fn from(err: std::io::Error) -> ErrorWrapper {
+        ErrorWrapper::Io(err)
+    }
+}
+
 impl From<std::str::Utf8Error> for ErrorWrapper {
     fn from(err: std::str::Utf8Error) -> ErrorWrapper {
         ErrorWrapper::Utf8(err)
@@ -146,9 +208,25 @@ impl From<x11rb::errors::ReplyError> for ErrorWrapper {
     }
 }
 
+impl From<x11rb::errors::ReplyOrIdError> for ErrorWrapper {
+    fn from(err: x11rb::errors::ReplyOrIdError) -> ErrorWrapper {
+        ErrorWrapper::ReplyOrId(err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
 
     #[test]
     fn test_errors() {}
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(ErrorWrapper::from(WmCtlError::Timeout).is_retryable());
+        assert!(ErrorWrapper::from(WmCtlError::PropertyNotFound("_NET_WM_DESKTOP".to_owned())).is_retryable());
+        assert!(!ErrorWrapper::from(WmCtlError::InvalidDesktop(9)).is_retryable());
+        assert!(!ErrorWrapper::from(WmCtlError::Unsupported("_NET_CLOSE_WINDOW".to_owned())).is_retryable());
+        assert!(!ErrorWrapper::from(std::io::Error::other("broken pipe")).is_retryable());
+    }
 }
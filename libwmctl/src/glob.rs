@@ -0,0 +1,120 @@
+// ## References
+// * https://man7.org/linux/man-pages/man7/glob.7.html
+//
+// ## Details
+// * Shell style wildcard matching against the whole of the text rather than paths i.e. `*` also
+//   matches `/` which is what is wanted when matching window titles.
+// * `*` matches any number of characters, `?` matches any single character, `[abc]` and `[a-z]`
+//   match a single character in the set or range, `[!abc]` negates the set and `\` escapes the
+//   following character.
+
+/// Check if the given text matches the given glob pattern
+///
+/// ### Arguments
+/// * `pattern` - glob pattern to match against
+/// * `text` - text to match
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+
+    // Position in the pattern after the last `*` and the text position it is matching from
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            p += 1;
+            star = Some((p, t));
+            continue;
+        }
+        if p < pattern.len() {
+            if let Some(next) = match_char(&pattern, p, text[t]) {
+                p = next;
+                t += 1;
+                continue;
+            }
+        }
+
+        // Backtrack to the last `*` and let it swallow one more character
+        match star {
+            Some((sp, st)) => {
+                p = sp;
+                t = st + 1;
+                star = Some((sp, st + 1));
+            },
+            None => return false,
+        }
+    }
+
+    // Trailing stars match the empty remainder
+    pattern[p..].iter().all(|x| *x == '*')
+}
+
+/// Match a single character against the pattern element at the given position
+///
+/// ### Returns
+/// * position of the next pattern element if the character matched
+fn match_char(pattern: &[char], p: usize, c: char) -> Option<usize> {
+    match pattern[p] {
+        '?' => Some(p + 1),
+        '\\' if p + 1 < pattern.len() => (pattern[p + 1] == c).then_some(p + 2),
+        '[' => match_set(pattern, p, c),
+        x => (x == c).then_some(p + 1),
+    }
+}
+
+/// Match a single character against the `[...]` set starting at the given position. An
+/// unterminated set is treated as a literal `[`.
+fn match_set(pattern: &[char], p: usize, c: char) -> Option<usize> {
+    let mut i = p + 1;
+    let negate = i < pattern.len() && (pattern[i] == '!' || pattern[i] == '^');
+    if negate {
+        i += 1;
+    }
+
+    // A `]` directly after the opening is part of the set
+    let start = i;
+    let mut matched = false;
+    while i < pattern.len() && (pattern[i] != ']' || i == start) {
+        if i + 2 < pattern.len() && pattern[i + 1] == '-' && pattern[i + 2] != ']' {
+            matched |= pattern[i] <= c && c <= pattern[i + 2];
+            i += 3;
+        } else {
+            matched |= pattern[i] == c;
+            i += 1;
+        }
+    }
+    if i >= pattern.len() {
+        return (c == '[').then_some(p + 1);
+    }
+    (matched != negate).then_some(i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*- Mozilla Firefox", "Rust - Mozilla Firefox"));
+        assert!(glob_match("*- Mozilla Firefox", "- Mozilla Firefox"));
+        assert!(!glob_match("*- Mozilla Firefox", "Rust - Mozilla Firefox Private"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("a*b*c", "aXXbYYbc"));
+        assert!(!glob_match("a*b*c", "aXXbYYb"));
+        assert!(glob_match("file?.txt", "file1.txt"));
+        assert!(!glob_match("file?.txt", "file.txt"));
+        assert!(glob_match("~/src/*", "~/src/wmctl/lib.rs"));
+    }
+
+    #[test]
+    fn test_glob_match_sets() {
+        assert!(glob_match("term[0-9]", "term5"));
+        assert!(!glob_match("term[0-9]", "termx"));
+        assert!(glob_match("term[!0-9]", "termx"));
+        assert!(glob_match("[]x]", "]"));
+        assert!(glob_match("a[bc", "a[bc"));
+        assert!(glob_match("\\*literal", "*literal"));
+        assert!(!glob_match("\\*literal", "xliteral"));
+    }
+}
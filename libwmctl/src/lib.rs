@@ -14,14 +14,21 @@
 //! for a variety of use cases separate from wmctl.
 
 mod atoms;
+mod auth;
+mod connection;
 mod error;
+#[cfg(feature = "glob")]
+mod glob;
 mod model;
+#[cfg(feature = "image")]
+mod png;
 mod window;
 mod winmgr;
 pub use atoms::*;
+pub use connection::WmConnection;
 pub use error::*;
 pub use model::*;
-pub use window::Window;
+pub use window::{AttentionGuard, Window};
 use winmgr::WinMgr;
 
 /// All essential symbols in a simple consumable form
@@ -39,12 +46,117 @@ pub mod prelude {
 /// since changing the instance won't ever happen and RwLock allows for multiple readers making this
 /// as efficient as possible.
 use std::sync::{OnceLock, RwLock};
+static INIT: OnceLock<RwLock<WinMgr>> = OnceLock::new();
 #[allow(non_snake_case)]
 fn WM() -> &'static RwLock<WinMgr> {
-    static INIT: OnceLock<RwLock<WinMgr>> = OnceLock::new();
     INIT.get_or_init(|| RwLock::new(WinMgr::connect().unwrap()))
 }
 
+/// Connect to the given display using credentials from the given Xauthority file rather than the
+/// one pointed to by $XAUTHORITY. This must be called before any other function as all following
+/// calls will share this connection.
+///
+/// ### Arguments
+/// * `display` - X11 display to connect to e.g. `:0`
+/// * `xauthority` - path to the Xauthority file to read credentials from
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::connect_with_auth(":0", Path::new("/home/user/.Xauthority")).unwrap();
+/// ```
+pub fn connect_with_auth(display: &str, xauthority: &std::path::Path) -> WmCtlResult<()> {
+    let wm = WinMgr::connect_with_auth(display, xauthority)?;
+    INIT.set(RwLock::new(wm)).map_err(|_| WmCtlError::AlreadyConnected)?;
+    Ok(())
+}
+
+/// Connect to the given display giving up if the connection isn't established within the given
+/// timeout. This must be called before any other function as all following calls will share this
+/// connection.
+///
+/// ### Arguments
+/// * `display` - X11 display to connect to e.g. `:0` or None to use $DISPLAY
+/// * `timeout` - how long to wait for the connection
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::connect_with_timeout(None, Duration::from_secs(5)).unwrap();
+/// ```
+pub fn connect_with_timeout(display: Option<&str>, timeout: std::time::Duration) -> WmCtlResult<()> {
+    let wm = WinMgr::connect_with_timeout(display, timeout)?;
+    INIT.set(RwLock::new(wm)).map_err(|_| WmCtlError::AlreadyConnected)?;
+    Ok(())
+}
+
+/// Get the file descriptor of the underlying X11 connection for use with `select` or `epoll`
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let fd = libwmctl::connection_fd();
+/// ```
+pub fn connection_fd() -> std::os::fd::RawFd {
+    WM().read().unwrap().connection_fd()
+}
+
+/// Block until the X server has processed all requests sent so far e.g. to order requests that
+/// depend on each other. The window manager may still act on client messages asynchronously.
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::sync().unwrap();
+/// ```
+pub fn sync() -> WmCtlResult<()> {
+    WM().read().unwrap().sync()
+}
+
+/// Select structure and property change events on the root window so that window management
+/// events are reported by `poll_event`
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::select_root_events().unwrap();
+/// ```
+pub fn select_root_events() -> WmCtlResult<()> {
+    WM().read().unwrap().select_root_events()
+}
+
+/// Get the next queued window management event without blocking
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::select_root_events().unwrap();
+/// while let Some(event) = libwmctl::poll_event().unwrap() {
+///     println!("{:?}", event);
+/// }
+/// ```
+pub fn poll_event() -> WmCtlResult<Option<Event>> {
+    WM().read().unwrap().poll_event()
+}
+
+/// Connect to the given display, run the given function with the connection then close it. This
+/// allows for one-off operations against a display other than the default one e.g. a nested
+/// Xephyr on `:99` without affecting the shared connection.
+///
+/// ### Arguments
+/// * `display` - X11 display to connect to e.g. `:99`
+/// * `f` - function to run with the connection
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let windows = libwmctl::with_display(":99", |conn| conn.windows(false)).unwrap();
+/// ```
+pub fn with_display<R>(display: &str, f: impl FnOnce(&WmConnection) -> WmCtlResult<R>) -> WmCtlResult<R> {
+    let conn = WmConnection::new(WinMgr::connect_to(display)?);
+    f(&conn)
+}
+
 /// Get window manager informational properties
 ///
 /// ### Examples
@@ -53,7 +165,118 @@ fn WM() -> &'static RwLock<WinMgr> {
 /// libwmctl::winmgr().unwrap();
 /// ```
 pub fn info() -> WmCtlResult<Info> {
-    Ok(WM().read().unwrap().info()?)
+    WM().read().unwrap().info()
+}
+
+/// Check if the window manager supports all the given functions e.g. to refuse an operation up
+/// front rather than only partially applying it
+///
+/// ### Arguments
+/// * `names` - atom names to lookup to see if they are all supported
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// if !libwmctl::supports_all(&["_NET_WM_STATE", "_NET_WM_DESKTOP", "_NET_MOVERESIZE_WINDOW"]) {
+///     println!("window manager can't restore layouts");
+/// }
+/// ```
+pub fn supports_all(names: &[&str]) -> bool {
+    WM().read().unwrap().supports_all(names)
+}
+
+/// Get the names of all the properties set on the root window as a sorted list e.g. to discover
+/// what the window manager publishes beyond what is modeled
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// for name in libwmctl::root_properties().unwrap() {
+///     println!("{}", name);
+/// }
+/// ```
+pub fn root_properties() -> WmCtlResult<Vec<String>> {
+    WM().read().unwrap().root_properties()
+}
+
+/// Get the monitor layout of the screen as cached on connect
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let layout = libwmctl::screen_layout().unwrap();
+/// let monitor = layout.monitor_containing(0, 0);
+/// ```
+pub fn screen_layout() -> WmCtlResult<ScreenLayout> {
+    WM().read().unwrap().screen_layout()
+}
+
+/// Get the monitors of the screen layout along with the work area of each monitor not reserved by
+/// dock struts. Names and indices match those of `screen_layout().monitors`.
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// for monitor in libwmctl::monitors().unwrap() {
+///     println!("{}: {:?}", monitor.name, monitor.work_area);
+/// }
+/// ```
+pub fn monitors() -> WmCtlResult<Vec<Monitor>> {
+    WM().read().unwrap().monitors()
+}
+
+/// Get the monitor driven by the RandR output with the given name e.g. HDMI-1 or the RandR monitor
+/// with the given name e.g. one defined with `xrandr --setmonitor`. Names stay the same across
+/// hotplugs unlike monitor indices which makes them the better choice for persisted configuration.
+/// * Returns None if no monitor or connected output driving one has the given name
+///
+/// ### Arguments
+/// * `name` - RandR output or monitor name
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let monitor = libwmctl::monitor_by_name("DP-2").unwrap();
+/// ```
+pub fn monitor_by_name(name: &str) -> WmCtlResult<Option<Monitor>> {
+    Ok(monitors()?.into_iter().find(|x| x.is_named(name)))
+}
+
+/// Get the monitor that has focus i.e. the one the active window's center lies on falling back on
+/// the monitor with the pointer when there is no active window and finally the primary monitor
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let monitor = libwmctl::focused_monitor().unwrap();
+/// println!("{}: {}x{}", monitor.name, monitor.w, monitor.h);
+/// ```
+pub fn focused_monitor() -> WmCtlResult<Monitor> {
+    let wm = WM().read().unwrap();
+    let layout = wm.screen_layout()?;
+
+    // Active window id is 0 when nothing is focused
+    let active = wm.active_window().ok().filter(|x| *x != 0);
+    let center = active
+        .and_then(|id| wm.window_visual_geometry(id).ok())
+        .map(|(x, y, w, h)| (x + w as i32 / 2, y + h as i32 / 2));
+    let monitor = center
+        .and_then(|(x, y)| layout.monitor_containing(x, y))
+        .or_else(|| wm.pointer_position().ok().and_then(|(x, y)| layout.monitor_containing(x, y)))
+        .or_else(|| layout.primary())
+        .ok_or(WmCtlError::InvalidMonitor(0))?;
+    Ok(monitor.clone())
+}
+
+/// Query the monitor layout again e.g. after a monitor has been plugged in or removed
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::refresh_screens().unwrap();
+/// ```
+pub fn refresh_screens() -> WmCtlResult<()> {
+    WM().write().unwrap().refresh_screens()
 }
 
 /// Get the active window
@@ -67,6 +290,89 @@ pub fn active() -> Window {
     Window::from(None)
 }
 
+/// Get the visual geometry of the active window corrected for its frame and client side
+/// decorations as per `Window::visual_geometry` resolving the active window only once
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let geometry = libwmctl::active_geometry().unwrap();
+/// ```
+pub fn active_geometry() -> WmCtlResult<Geometry> {
+    let wm = WM().read().unwrap();
+    let id = wm.active_window()?;
+    if id == x11rb::NONE {
+        return Err(WmCtlError::ActiveWinNotFound.into());
+    }
+    Ok(Geometry::from(wm.window_visual_geometry(id)?))
+}
+
+/// Nudge the active window by the given pixel delta e.g. for arrow key bindings. The window is
+/// kept at least partially on screen.
+///
+/// ### Arguments
+/// * `dx` - pixels to move the window right or left if negative
+/// * `dy` - pixels to move the window down or up if negative
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::move_active_relative(10, 0).unwrap();
+/// ```
+pub fn move_active_relative(dx: i32, dy: i32) -> WmCtlResult<()> {
+    let (id, geometry, bounds) = {
+        let wm = WM().read().unwrap();
+        let id = wm.active_window()?;
+        if id == x11rb::NONE {
+            return Err(WmCtlError::ActiveWinNotFound.into());
+        }
+        let layout = wm.screen_layout()?;
+        let geometry = Geometry::from(wm.window_visual_geometry(id)?);
+        (id, geometry, Geometry::new(layout.x, layout.y, layout.w, layout.h))
+    };
+    let (x, y) = window::translate_nudge(&geometry, &bounds, dx, dy);
+    window(id).move_visible_to(x, y)
+}
+
+/// Move the active window to the given monitor keeping its relative position within the monitor
+/// where it fits e.g. for a throw window to other screen key binding. Maximized windows are
+/// maximized again on the target monitor.
+/// * Returns InvalidMonitor if there is no monitor at the given index
+///
+/// ### Arguments
+/// * `index` - index of the monitor in the screen layout
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::move_active_to_monitor(1).unwrap();
+/// ```
+pub fn move_active_to_monitor(index: usize) -> WmCtlResult<()> {
+    let (id, layout) = {
+        let wm = WM().read().unwrap();
+        (wm.active_window()?, wm.screen_layout()?)
+    };
+    if id == x11rb::NONE {
+        return Err(WmCtlError::ActiveWinNotFound.into());
+    }
+    let target = layout.monitors.get(index).ok_or(WmCtlError::InvalidMonitor(index))?;
+
+    // Maximized windows are usually pinned in place by the window manager
+    let win = window(id);
+    let maximized = win.maximized();
+    if maximized {
+        win.unmaximize()?;
+    }
+    let geometry = Geometry::from(win.visual_geometry()?);
+    let source = layout.monitor_overlapping(&geometry).or_else(|| layout.primary()).unwrap_or(target);
+    let (x, y) = window::translate_to_monitor(&geometry, source, target);
+    win.move_visible_to(x, y)?;
+    if maximized {
+        win.maximize()?;
+    }
+    Ok(())
+}
+
 /// Get the window by id
 ///
 /// ### Arguments
@@ -99,6 +405,21 @@ pub fn windows(hidden: bool) -> WmCtlResult<Vec<Window>> {
         .map(|&id| Ok(Window::new(id)))
         .collect::<WmCtlResult<Vec<Window>>>()
 }
+
+/// Get the details of all managed windows for a listing like `wmctrl -l` gathered in a single
+/// batched snapshot rather than querying each field of each window separately
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// for row in libwmctl::list_windows_detailed().unwrap() {
+///     println!("{:#x} {} {} {}", row.id, row.desktop, row.host, row.title);
+/// }
+/// ```
+pub fn list_windows_detailed() -> WmCtlResult<Vec<WindowRow>> {
+    WM().read().unwrap().window_rows()
+}
+
 /// Retrieve a list of windows in the stacking order.
 ///
 /// This function fetches the windows managed by the window manager in the order they are stacked
@@ -132,6 +453,99 @@ pub fn windows_by_stack_order() -> WmCtlResult<Vec<Window>> {
         .collect::<WmCtlResult<Vec<Window>>>()
 }
 
+/// Get the windows on the given desktop in top to bottom stacking order e.g. for a per desktop
+/// alt-tab. Sticky windows are included as they appear on every desktop. On viewport based window
+/// managers desktops are the viewport cells and windows that close while the list is being built
+/// are skipped.
+///
+/// ### Arguments
+/// * `desktop` - desktop to get the windows for numbered from 1
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// for win in libwmctl::windows_stacking_on_desktop(1).unwrap() {
+///     println!("Window ID: {}", win.id);
+/// }
+/// ```
+pub fn windows_stacking_on_desktop(desktop: u32) -> WmCtlResult<Vec<Window>> {
+    let wm = WM().read().unwrap();
+    if desktop == 0 || desktop > wm.desktop_position()?.1 {
+        return Err(WmCtlError::InvalidDesktop(desktop).into());
+    }
+
+    // Filter in place to preserve the stacking order
+    let mut windows = vec![];
+    for id in wm.windows_by_stack_order()?.into_iter().rev() {
+        let Ok(win_desktop) = wm.window_effective_desktop(id) else { continue };
+        if win_desktop == -1 || win_desktop == desktop as i32 {
+            windows.push(Window::new(id));
+        }
+    }
+    Ok(windows)
+}
+
+/// Get the most recently active window for each desktop by taking the topmost window in the
+/// stacking order on each desktop. Sticky windows are skipped as they appear on every desktop. On
+/// viewport based window managers desktops are the viewport cells and windows that close while the
+/// list is being built are skipped.
+///
+/// ### Returns
+/// * the topmost window for each desktop in desktop order or None if the desktop has no windows
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// for (i, win) in libwmctl::active_window_on_each_desktop().unwrap().iter().enumerate() {
+///     println!("Desktop {}: {:?}", i + 1, win.as_ref().map(|x| x.id));
+/// }
+/// ```
+pub fn active_window_on_each_desktop() -> WmCtlResult<Vec<Option<Window>>> {
+    let desktops = WM().read().unwrap().desktop_position()?.1 as usize;
+    let mut active: Vec<Option<Window>> = vec![None; desktops];
+    for win in windows_by_stack_order()? {
+        let Ok(desktop) = win.effective_desktop() else { continue };
+        if desktop < 1 || desktop as usize > desktops {
+            continue;
+        }
+        let slot = &mut active[desktop as usize - 1];
+        if slot.is_none() {
+            *slot = Some(win);
+        }
+    }
+    Ok(active)
+}
+
+/// Move every managed window on the source desktop to the destination desktop. Sticky windows are
+/// skipped as they already appear on every desktop. On viewport based window managers desktops are
+/// the viewport cells and windows that close while the list is being built are skipped.
+///
+/// ### Arguments
+/// * `src` - desktop to move windows from numbered from 1
+/// * `dst` - desktop to move windows to numbered from 1
+///
+/// ### Returns
+/// * the number of windows moved
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let moved = libwmctl::move_all_from_desktop(2, 1).unwrap();
+/// ```
+pub fn move_all_from_desktop(src: u32, dst: u32) -> WmCtlResult<usize> {
+    if src == 0 || src > WM().read().unwrap().desktop_position()?.1 {
+        return Err(WmCtlError::InvalidDesktop(src).into());
+    }
+    let mut ids = vec![];
+    for win in windows(false)? {
+        if win.effective_desktop().is_ok_and(|x| x == src as i32) {
+            ids.push(win.id);
+        }
+    }
+    WM().read().unwrap().move_windows_to_desktop(&ids, dst)?;
+    Ok(ids.len())
+}
+
 /// Get the first window that matches the given class
 ///
 /// ### Arguments
@@ -151,7 +565,436 @@ pub fn first_by_class(class: &str) -> Option<Window> {
         .unwrap()
         .iter()
         .find(|x| x.class().unwrap_or("".to_string()).to_lowercase() == class.to_lowercase())
-        .map_or(None, |x| Some(x.clone()))
+        .cloned()
+}
+
+/// Get all the windows that match the given class ignoring case in top to bottom stacking order
+///
+/// ### Arguments
+/// * `class` - the class to match against
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// for win in libwmctl::all_by_class("firefox").unwrap() {
+///     println!("{}", win.name().unwrap());
+/// }
+/// ```
+pub fn all_by_class(class: &str) -> WmCtlResult<Vec<Window>> {
+    let class = class.to_lowercase();
+    Ok(windows_by_stack_order()?
+        .into_iter()
+        .filter(|x| x.class().unwrap_or_default().to_lowercase() == class)
+        .collect())
+}
+
+/// Get the most recently used window that matches the given class ignoring case i.e. the one
+/// highest in the stacking order. Unlike `first_by_class` which picks from the age ordered client
+/// list this gives e.g. the last used browser window.
+///
+/// ### Arguments
+/// * `class` - the class to match against
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// if let Some(win) = libwmctl::most_recent_by_class("firefox").unwrap() {
+///     win.activate().unwrap();
+/// }
+/// ```
+pub fn most_recent_by_class(class: &str) -> WmCtlResult<Option<Window>> {
+    Ok(all_by_class(class)?.into_iter().next())
+}
+
+/// Get the first window owned by the given process
+///
+/// ### Arguments
+/// * `pid` - the process id to match against
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let win = libwmctl::first_by_pid(1234).unwrap();
+/// ```
+pub fn first_by_pid(pid: i32) -> Option<Window> {
+    windows(false).ok()?.into_iter().find(|x| x.pid().is_ok_and(|x| x == pid))
+}
+
+/// Get the first window whose name contains the given text ignoring case. The name used is the
+/// first non empty one of _NET_WM_VISIBLE_NAME, _NET_WM_NAME and WM_NAME and windows whose name
+/// can't be read are skipped. Use `Window::title_matches` with the `glob` feature for glob style
+/// matching or `first_by_name_regex` with the `regex` feature for regular expressions.
+///
+/// ### Arguments
+/// * `text` - the text to search window names for
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let win = libwmctl::first_by_name("mozilla firefox").unwrap();
+/// ```
+pub fn first_by_name(text: &str) -> Option<Window> {
+    let text = text.to_lowercase();
+    windows(false).ok()?.into_iter().find(|x| x.name().is_ok_and(|x| x.to_lowercase().contains(&text)))
+}
+
+/// Get all the windows whose name contains the given text ignoring case. The name used is the
+/// first non empty one of _NET_WM_VISIBLE_NAME, _NET_WM_NAME and WM_NAME and windows whose name
+/// can't be read are skipped.
+///
+/// ### Arguments
+/// * `text` - the text to search window names for
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// for win in libwmctl::all_by_name("terminal").unwrap() {
+///     println!("{}", win.id());
+/// }
+/// ```
+pub fn all_by_name(text: &str) -> WmCtlResult<Vec<Window>> {
+    let text = text.to_lowercase();
+    Ok(windows(false)?.into_iter().filter(|x| x.name().is_ok_and(|x| x.to_lowercase().contains(&text))).collect())
+}
+
+/// Get the first window whose name matches the given regular expression anywhere in the name
+/// unless anchored with `^` and `$`. The name used is the first non empty one of
+/// _NET_WM_VISIBLE_NAME, _NET_WM_NAME and WM_NAME and windows whose name can't be read are
+/// skipped. Matching is case sensitive unless the pattern starts with `(?i)`. Requires the `regex`
+/// feature.
+///
+/// ### Arguments
+/// * `pattern` - the regular expression to match window names against
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let win = libwmctl::first_by_name_regex(r"(?i)- mozilla firefox$").unwrap();
+/// ```
+#[cfg(feature = "regex")]
+pub fn first_by_name_regex(pattern: &str) -> WmCtlResult<Option<Window>> {
+    let re = regex::Regex::new(pattern).map_err(|x| WmCtlError::InvalidPattern(x.to_string()))?;
+    Ok(windows(false)?.into_iter().find(|x| x.name().is_ok_and(|x| re.is_match(&x))))
+}
+
+/// Get the managed windows addressed by the given selector
+///
+/// ### Arguments
+/// * `sel` - selector to match the windows against
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let sel = Selector::try_from("class:firefox").unwrap();
+/// for win in libwmctl::select(&sel).unwrap() {
+///     println!("Window ID: {}", win.id);
+/// }
+/// ```
+pub fn select(sel: &Selector) -> WmCtlResult<Vec<Window>> {
+    // Resolve the active window once rather than for every window
+    let sel = match sel {
+        Selector::Active => Selector::ById(WM().read().unwrap().active_window()?),
+        _ => sel.clone(),
+    };
+    Ok(windows(false)?.into_iter().filter(|x| x.matches(&sel)).collect())
+}
+
+/// Get the active window falling back on the first window addressed by the given selector when
+/// there is no active window e.g. act on the focused window or else the first terminal
+///
+/// ### Arguments
+/// * `sel` - selector to resolve when there is no active window
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let win = libwmctl::active_or(&Selector::ByClass("kitty".to_owned())).unwrap();
+/// ```
+pub fn active_or(sel: &Selector) -> WmCtlResult<Window> {
+    let id = WM().read().unwrap().active_window().unwrap_or(x11rb::NONE);
+    if id != x11rb::NONE {
+        return Ok(window(id));
+    }
+    select(sel)?.into_iter().next().ok_or_else(|| WmCtlError::WinNotFound(sel.to_string()).into())
+}
+
+/// Raise all the windows that match the given class keeping their relative stacking order such
+/// that the most recently active window of the class ends up on top
+///
+/// ### Arguments
+/// * `class` - the class to match against
+/// * `all_desktops` - include windows on other desktops rather than only the active desktop
+///
+/// ### Returns
+/// * the number of windows raised
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let raised = libwmctl::raise_all_by_class("firefox", false).unwrap();
+/// ```
+pub fn raise_all_by_class(class: &str, all_desktops: bool) -> WmCtlResult<usize> {
+    let desktop = active_desktop()? as i32;
+    let mut count = 0;
+
+    // Raise from the bottom of the stack up to preserve the relative order
+    for win in windows_by_stack_order()?.iter().rev() {
+        if win.class().unwrap_or("".to_string()).to_lowercase() != class.to_lowercase() {
+            continue;
+        }
+        if !all_desktops {
+            let other = win.desktop()?;
+            if other != desktop && other != -1 {
+                continue;
+            }
+        }
+        win.raise()?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Gracefully close all the windows that match the given class and optionally force any that are
+/// still open after the given duration e.g. to quit all browser windows
+///
+/// ### Arguments
+/// * `class` - the class to match against
+/// * `force_after` - kill the applications whose windows are still open after this long
+///
+/// ### Returns
+/// * the number of windows closed or asked to close when not forcing
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::close_all_by_class("firefox", Some(Duration::from_secs(5))).unwrap();
+/// ```
+pub fn close_all_by_class(class: &str, force_after: Option<std::time::Duration>) -> WmCtlResult<usize> {
+    let matching = windows(false)?.into_iter().filter(|x| x.class_matches_any(&[class])).collect::<Vec<_>>();
+    for win in matching.iter() {
+        // Windows that closed on their own in the meantime are already done
+        if let Err(e) = win.close() {
+            if win.exists()? {
+                return Err(e);
+            }
+        }
+    }
+    let timeout = match force_after {
+        Some(timeout) => timeout,
+        None => return Ok(matching.len()),
+    };
+
+    // Wait for the windows to close on their own checking all of them each time around
+    let total = matching.len();
+    let deadline = std::time::Instant::now() + timeout;
+    let mut open = matching;
+    loop {
+        open.retain(|x| x.exists().unwrap_or(false));
+        if open.is_empty() || std::time::Instant::now() >= deadline {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+
+    // Windows may still close between the last check and the kill
+    for win in open.iter() {
+        if let Err(e) = win.kill() {
+            if win.exists()? {
+                return Err(e);
+            }
+        }
+    }
+    Ok(total)
+}
+
+/// Toggle the given window between maximized and unmaximized based on its current state
+///
+/// ### Arguments
+/// * `id` - id of the window to manipulate
+///
+/// ### Returns
+/// * `true` if the window was maximized, `false` if it was unmaximized
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::toggle_maximize(1234).unwrap();
+/// ```
+pub fn toggle_maximize(id: u32) -> WmCtlResult<bool> {
+    window(id).toggle_maximize()
+}
+
+/// Cascade the given windows diagonally from the origin of the focused monitor's work area stepping
+/// each by the given offsets. Windows are given a uniform size of 60% of the work area and raised
+/// in order so that the last window ends up on top.
+///
+/// ### Arguments
+/// * `windows` - windows to cascade in order
+/// * `offset_x` - horizontal step between windows
+/// * `offset_y` - vertical step between windows
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::cascade(&libwmctl::windows(false).unwrap(), 30, 30).unwrap();
+/// ```
+pub fn cascade(windows: &[Window], offset_x: i32, offset_y: i32) -> WmCtlResult<()> {
+    let area = focused_monitor()?.work_area;
+    let geometries = window::translate_cascade(windows.len(), offset_x, offset_y, &area);
+    let wm = WM().read().unwrap();
+    for (win, (x, y, w, h)) in windows.iter().zip(geometries) {
+        if win.maximized() {
+            win.unmaximize()?;
+        }
+        wm.move_resize_window(win.id, None, Some(x), Some(y), Some(w), Some(h))?;
+        wm.focus_window(win.id)?;
+    }
+    Ok(())
+}
+
+/// Toggle the active window in and out of fullscreen based on its current state
+///
+/// ### Returns
+/// * `true` if the window is now fullscreen, `false` if it no longer is
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::toggle_fullscreen_active().unwrap();
+/// ```
+pub fn toggle_fullscreen_active() -> WmCtlResult<bool> {
+    let id = WM().read().unwrap().active_window()?;
+    if id == x11rb::NONE {
+        return Err(WmCtlError::ActiveWinNotFound.into());
+    }
+    window(id).toggle_fullscreen()
+}
+
+/// Snap the active window to the given half of the work area of the monitor it is on. The window
+/// is unmaximized first and its frame is accounted for so that it sits flush against the edge.
+///
+/// ### Arguments
+/// * `edge` - side of the monitor to snap the window against
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::snap_active(Edge::Left).unwrap();
+/// ```
+pub fn snap_active(edge: Edge) -> WmCtlResult<()> {
+    let id = WM().read().unwrap().active_window()?;
+    if id == x11rb::NONE {
+        return Err(WmCtlError::ActiveWinNotFound.into());
+    }
+    window(id).snap(edge)
+}
+
+/// Place the two windows side by side in the left and right halves of the work area of the focused
+/// monitor. Both windows are unmaximized first and their frames are accounted for so that they
+/// meet flush in the center.
+///
+/// ### Arguments
+/// * `left` - window to place in the left half
+/// * `right` - window to place in the right half
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::split_screen(&window(1234), &window(5678)).unwrap();
+/// ```
+pub fn split_screen(left: &Window, right: &Window) -> WmCtlResult<()> {
+    let monitor = focused_monitor()?;
+    left.snap_on(&monitor, Edge::Left)?;
+    right.snap_on(&monitor, Edge::Right)
+}
+
+/// Capture the arrangement of every managed window such that it can be persisted and reapplied
+/// later with `restore_layout`
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let layout = libwmctl::save_layout().unwrap();
+/// std::fs::write("layout", layout.to_string()).unwrap();
+/// ```
+pub fn save_layout() -> WmCtlResult<Layout> {
+    let wm = WM().read().unwrap();
+    let mut entries = vec![];
+    for id in wm.windows(false)? {
+        // Position is the visible corner while size is what is requested when resizing
+        let (x, y, _, _) = wm.window_visual_geometry(id)?;
+        let (_, _, w, h) = wm.window_geometry(id)?;
+        entries.push(LayoutEntry {
+            class: wm.window_class(id)?,
+            role: wm.window_role(id).unwrap_or_default(),
+            desktop: wm.window_desktop(id)?,
+            x,
+            y,
+            w,
+            h,
+            states: wm.window_state(id)?.into_iter().filter(is_restorable).collect(),
+        });
+    }
+    Ok(Layout { entries })
+}
+
+/// Reapply the given saved arrangement to the current windows. Windows are matched by class and
+/// role then by class alone such that each saved entry is applied to at most one window.
+/// Entries without a matching window are skipped.
+///
+/// ### Arguments
+/// * `layout` - saved arrangement to reapply
+///
+/// ### Returns
+/// * number of windows that were restored
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let layout = Layout::try_from(std::fs::read_to_string("layout").unwrap()).unwrap();
+/// libwmctl::restore_layout(&layout).unwrap();
+/// ```
+pub fn restore_layout(layout: &Layout) -> WmCtlResult<usize> {
+    let windows = {
+        let wm = WM().read().unwrap();
+        let mut windows = vec![];
+        for id in wm.windows(false)? {
+            windows.push((id, wm.window_class(id)?, wm.window_role(id).unwrap_or_default()));
+        }
+        windows
+    };
+
+    let matches = layout.match_windows(&windows);
+    for (i, id) in matches.iter() {
+        window(*id).restore(&layout.entries[*i])?;
+    }
+    Ok(matches.len())
+}
+
+/// Get a summary of the desktops including their names, the active desktop and the number of
+/// windows on each for status bars to consume in a single call each tick
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let summary = libwmctl::workspace_summary().unwrap();
+/// println!("{}/{}", summary.active, summary.desktops);
+/// ```
+pub fn workspace_summary() -> WmCtlResult<WorkspaceSummary> {
+    WM().read().unwrap().workspace_summary()
+}
+
+/// Get the names of the desktops in desktop order with missing names defaulting to `Desktop N`
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// for (i, name) in libwmctl::desktop_names().unwrap().iter().enumerate() {
+///     println!("{}: {}", i + 1, name);
+/// }
+/// ```
+pub fn desktop_names() -> WmCtlResult<Vec<String>> {
+    WM().read().unwrap().desktop_names()
 }
 
 /// Get the active desktop
@@ -166,10 +1009,168 @@ pub fn active_desktop() -> WmCtlResult<u32> {
     WM().read().unwrap().active_desktop()
 }
 
+/// Request a larger virtual desktop on window managers that support large desktops e.g. to scroll
+/// across a desktop twice the width of the screen
+///
+/// ### Arguments
+/// * `w` - width of the virtual desktop
+/// * `h` - height of the virtual desktop
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::set_desktop_geometry(3840, 1080).unwrap();
+/// ```
+pub fn set_desktop_geometry(w: u32, h: u32) -> WmCtlResult<()> {
+    WM().read().unwrap().set_desktop_geometry(w, h)
+}
+
+/// Switch to the given desktop
+/// id from 1 and up (like window desktop), on viewport based window managers the viewport is moved
+/// to the given viewport cell instead
+/// * Returns InvalidDesktop if the desktop is 0 or beyond the number of desktops
+///
+/// ### Arguments
+/// * `desktop` - desktop to switch to numbered from 1
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::switch_desktop(2).unwrap();
+/// ```
+pub fn switch_desktop(desktop: u32) -> WmCtlResult<()> {
+    WM().read().unwrap().set_active_desktop(desktop)
+}
+
+/// Set the active desktop, the counterpart of `active_desktop()` and the same as `switch_desktop()`
+/// id from 1 and up (like window desktop) such that the value read can be written back as is
+/// * Returns InvalidDesktop if the desktop is 0 or beyond the number of desktops
+///
+/// ### Arguments
+/// * `desktop` - desktop to switch to numbered from 1
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let desktop = libwmctl::active_desktop().unwrap();
+/// libwmctl::set_active_desktop(desktop).unwrap();
+/// ```
+pub fn set_active_desktop(desktop: u32) -> WmCtlResult<()> {
+    WM().read().unwrap().set_active_desktop(desktop)
+}
+
+/// Switch to the next desktop wrapping around to the first desktop after the last
+///
+/// ### Returns
+/// * the desktop switched to numbered from 1
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::next_desktop().unwrap();
+/// ```
+pub fn next_desktop() -> WmCtlResult<u32> {
+    WM().read().unwrap().cycle_desktop(1)
+}
+
+/// Switch to the previous desktop wrapping around to the last desktop before the first
+///
+/// ### Returns
+/// * the desktop switched to numbered from 1
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::prev_desktop().unwrap();
+/// ```
+pub fn prev_desktop() -> WmCtlResult<u32> {
+    WM().read().unwrap().cycle_desktop(-1)
+}
+
+/// Switch to the given desktop failing with `InvalidDesktop` if it is beyond the live number of
+/// desktops
+///
+/// ### Arguments
+/// * `desktop` - desktop to switch to numbered from 1
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// libwmctl::nth_desktop(3).unwrap();
+/// ```
+pub fn nth_desktop(desktop: u32) -> WmCtlResult<()> {
+    WM().read().unwrap().nth_desktop(desktop)
+}
+
+/// Run the given operation retrying it with an exponential backoff starting at 50ms when it fails
+/// with an error that is likely transient e.g. a window being manipulated while it is still being
+/// mapped. Errors that aren't retryable or that persist past the last attempt are returned as is.
+///
+/// ### Arguments
+/// * `attempts` - maximum number of times to run the operation, at least once
+/// * `f` - operation to run
+///
+/// ### Examples
+/// ```ignore
+/// use libwmctl::prelude::*;
+/// let win = window(12345);
+/// libwmctl::with_retry(5, || win.activate()).unwrap();
+/// ```
+pub fn with_retry<R>(attempts: u32, f: impl Fn() -> WmCtlResult<R>) -> WmCtlResult<R> {
+    let mut delay = std::time::Duration::from_millis(50);
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Err(err) if attempt < attempts && err.is_retryable() => {
+                tracing::debug!("with_retry: attempt: {}, delay: {:?}, error: {}", attempt, delay, err);
+                std::thread::sleep(delay);
+                delay = (delay * 2).min(std::time::Duration::from_secs(1));
+                attempt += 1;
+            },
+            result => return result,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use std::cell::Cell;
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn test_with_retry() {
+        // Transient errors are retried until the operation succeeds
+        let calls = Cell::new(0);
+        let result = with_retry(3, || {
+            calls.set(calls.get() + 1);
+            if calls.get() < 2 {
+                return Err(WmCtlError::Timeout.into());
+            }
+            Ok(calls.get())
+        });
+        assert_eq!(result.unwrap(), 2);
+
+        // Attempts are bounded and the last error is returned
+        calls.set(0);
+        let result: WmCtlResult<()> = with_retry(2, || {
+            calls.set(calls.get() + 1);
+            Err(WmCtlError::Timeout.into())
+        });
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 2);
+
+        // Other errors are returned immediately
+        calls.set(0);
+        let result: WmCtlResult<()> = with_retry(5, || {
+            calls.set(calls.get() + 1);
+            Err(WmCtlError::InvalidDesktop(0).into())
+        });
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 1);
+    }
 }
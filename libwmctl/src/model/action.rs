@@ -0,0 +1,78 @@
+use crate::{atoms::AtomCollection, WmCtlError, WmCtlResult};
+use std::fmt;
+
+/// WinAction provides an easy way to identify the different actions the window manager allows
+/// for a window as listed in _NET_WM_ALLOWED_ACTIONS
+#[derive(Debug, Clone, PartialEq)]
+pub enum WinAction {
+    Above,         // the window may be placed above others
+    Below,         // the window may be placed below others
+    ChangeDesktop, // the window may be moved between desktops
+    Close,         // the window may be closed
+    Fullscreen,    // the window may be fullscreened
+    MaxHorz,       // the window may be maximized horizontally
+    MaxVert,       // the window may be maximized vertically
+    Minimize,      // the window may be minimized
+    Move,          // the window may be moved
+    Resize,        // the window may be resized
+    Shade,         // the window may be rolled up
+    Stick,         // the window may be shown on all virtual desktops
+}
+
+// Convert from u32 to WinAction
+impl WinAction {
+    pub fn from(atoms: &AtomCollection, val: u32) -> WmCtlResult<WinAction> {
+        if val == atoms._NET_WM_ACTION_ABOVE {
+            Ok(WinAction::Above)
+        } else if val == atoms._NET_WM_ACTION_BELOW {
+            Ok(WinAction::Below)
+        } else if val == atoms._NET_WM_ACTION_CHANGE_DESKTOP {
+            Ok(WinAction::ChangeDesktop)
+        } else if val == atoms._NET_WM_ACTION_CLOSE {
+            Ok(WinAction::Close)
+        } else if val == atoms._NET_WM_ACTION_FULLSCREEN {
+            Ok(WinAction::Fullscreen)
+        } else if val == atoms._NET_WM_ACTION_MAXIMIZE_HORZ {
+            Ok(WinAction::MaxHorz)
+        } else if val == atoms._NET_WM_ACTION_MAXIMIZE_VERT {
+            Ok(WinAction::MaxVert)
+        } else if val == atoms._NET_WM_ACTION_MINIMIZE {
+            Ok(WinAction::Minimize)
+        } else if val == atoms._NET_WM_ACTION_MOVE {
+            Ok(WinAction::Move)
+        } else if val == atoms._NET_WM_ACTION_RESIZE {
+            Ok(WinAction::Resize)
+        } else if val == atoms._NET_WM_ACTION_SHADE {
+            Ok(WinAction::Shade)
+        } else if val == atoms._NET_WM_ACTION_STICK {
+            Ok(WinAction::Stick)
+        } else {
+            Err(WmCtlError::InvalidWinAction(val).into())
+        }
+    }
+
+    /// Convert the WinAction back into its atom value
+    pub fn atom(&self, atoms: &AtomCollection) -> u32 {
+        match self {
+            WinAction::Above => atoms._NET_WM_ACTION_ABOVE,
+            WinAction::Below => atoms._NET_WM_ACTION_BELOW,
+            WinAction::ChangeDesktop => atoms._NET_WM_ACTION_CHANGE_DESKTOP,
+            WinAction::Close => atoms._NET_WM_ACTION_CLOSE,
+            WinAction::Fullscreen => atoms._NET_WM_ACTION_FULLSCREEN,
+            WinAction::MaxHorz => atoms._NET_WM_ACTION_MAXIMIZE_HORZ,
+            WinAction::MaxVert => atoms._NET_WM_ACTION_MAXIMIZE_VERT,
+            WinAction::Minimize => atoms._NET_WM_ACTION_MINIMIZE,
+            WinAction::Move => atoms._NET_WM_ACTION_MOVE,
+            WinAction::Resize => atoms._NET_WM_ACTION_RESIZE,
+            WinAction::Shade => atoms._NET_WM_ACTION_SHADE,
+            WinAction::Stick => atoms._NET_WM_ACTION_STICK,
+        }
+    }
+}
+
+// Implement format! support
+impl fmt::Display for WinAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
+    }
+}
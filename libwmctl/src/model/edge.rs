@@ -0,0 +1,43 @@
+use crate::WmCtlError;
+use std::{convert, fmt};
+
+/// Edge provides the sides of a monitor a window can be snapped against such that it fills that
+/// half of the monitor's work area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+// Implement format! support
+impl fmt::Display for Edge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
+    }
+}
+
+// Convert from &str to Edge
+impl convert::TryFrom<&str> for Edge {
+    type Error = WmCtlError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        match val.to_lowercase().as_ref() {
+            "left" => Ok(Edge::Left),
+            "right" => Ok(Edge::Right),
+            "top" => Ok(Edge::Top),
+            "bottom" => Ok(Edge::Bottom),
+            _ => Err(WmCtlError::InvalidWinPosition(val.to_string())),
+        }
+    }
+}
+
+// Convert from a String to an Edge
+impl convert::TryFrom<String> for Edge {
+    type Error = WmCtlError;
+
+    fn try_from(val: String) -> Result<Self, Self::Error> {
+        Edge::try_from(val.as_str())
+    }
+}
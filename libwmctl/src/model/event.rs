@@ -0,0 +1,53 @@
+use x11rb::protocol::Event as XEvent;
+
+/// Event provides the subset of X11 events relevant to window management
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    PropertyChanged {
+        window: u32,
+        atom: u32,
+    },
+    WindowCreated {
+        window: u32,
+    },
+    WindowDestroyed {
+        window: u32,
+    },
+    WindowMapped {
+        window: u32,
+    },
+    WindowUnmapped {
+        window: u32,
+    },
+    WindowConfigured {
+        window: u32,
+        x: i32,
+        y: i32,
+        w: u32,
+        h: u32,
+    },
+}
+
+// Convert from the raw X11 event dropping those that aren't relevant
+impl Event {
+    pub(crate) fn from(event: XEvent) -> Option<Event> {
+        match event {
+            XEvent::PropertyNotify(e) => Some(Event::PropertyChanged {
+                window: e.window,
+                atom: e.atom,
+            }),
+            XEvent::CreateNotify(e) => Some(Event::WindowCreated { window: e.window }),
+            XEvent::DestroyNotify(e) => Some(Event::WindowDestroyed { window: e.window }),
+            XEvent::MapNotify(e) => Some(Event::WindowMapped { window: e.window }),
+            XEvent::UnmapNotify(e) => Some(Event::WindowUnmapped { window: e.window }),
+            XEvent::ConfigureNotify(e) => Some(Event::WindowConfigured {
+                window: e.window,
+                x: e.x as i32,
+                y: e.y as i32,
+                w: e.width as u32,
+                h: e.height as u32,
+            }),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// Core protocol event mask bits in bit order
+const NAMES: [&str; 25] = [
+    "KeyPress",
+    "KeyRelease",
+    "ButtonPress",
+    "ButtonRelease",
+    "EnterWindow",
+    "LeaveWindow",
+    "PointerMotion",
+    "PointerMotionHint",
+    "Button1Motion",
+    "Button2Motion",
+    "Button3Motion",
+    "Button4Motion",
+    "Button5Motion",
+    "ButtonMotion",
+    "KeymapState",
+    "Exposure",
+    "VisibilityChange",
+    "StructureNotify",
+    "ResizeRedirect",
+    "SubstructureNotify",
+    "SubstructureRedirect",
+    "FocusChange",
+    "PropertyChange",
+    "ColorMapChange",
+    "OwnerGrabButton",
+];
+
+/// EventMasks provides the events selected on a window for diagnosing why events sent to or
+/// expected from the window aren't being delivered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventMasks {
+    pub all: u32,   // events selected on the window by all clients combined
+    pub yours: u32, // events selected on the window by this client
+}
+
+impl EventMasks {
+    /// Get the names of the events set in the given mask
+    ///
+    /// ### Arguments
+    /// * `mask` - event mask to name e.g. `all` or `yours`
+    pub fn names(mask: u32) -> Vec<&'static str> {
+        NAMES.iter().enumerate().filter(|(i, _)| mask & (1 << i) != 0).map(|(_, x)| *x).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_mask_names() {
+        assert_eq!(EventMasks::names(0), Vec::<&str>::new());
+        assert_eq!(EventMasks::names(1), vec!["KeyPress"]);
+        assert_eq!(EventMasks::names((1 << 17) | (1 << 22)), vec!["StructureNotify", "PropertyChange"]);
+    }
+}
@@ -10,22 +10,24 @@ use std::fmt;
 #[derive(Debug, Clone, PartialEq)]
 pub enum Gravity {
     Unmap,
+    NorthWest,
     Center,
+    Static,
 }
 
 // Implement format! support
 impl fmt::Display for Gravity {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            _ => write!(f, "{}", format!("{:?}", self).to_lowercase()),
-        }
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
     }
 }
 
 impl From<u32> for Gravity {
     fn from(val: u32) -> Self {
         match val {
+            1 => Gravity::NorthWest,
             5 => Gravity::Center,
+            10 => Gravity::Static,
             _ => Gravity::Unmap,
         }
     }
@@ -34,7 +36,9 @@ impl From<u32> for Gravity {
 impl From<Gravity> for u32 {
     fn from(val: Gravity) -> Self {
         match val {
+            Gravity::NorthWest => 1,
             Gravity::Center => 5,
+            Gravity::Static => 10,
             Gravity::Unmap => 0,
         }
     }
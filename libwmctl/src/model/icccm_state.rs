@@ -0,0 +1,31 @@
+use std::fmt;
+
+use crate::{WmCtlError, WmCtlResult};
+
+/// IcccmState provides an easy way to identify the ICCCM WM_STATE values the window manager sets
+/// on the windows it manages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcccmState {
+    Withdrawn,
+    Normal,
+    Iconic,
+}
+
+// Convert from u32 to state
+impl IcccmState {
+    pub fn from(val: u32) -> WmCtlResult<IcccmState> {
+        match val {
+            0 => Ok(IcccmState::Withdrawn),
+            1 => Ok(IcccmState::Normal),
+            3 => Ok(IcccmState::Iconic),
+            _ => Err(WmCtlError::InvalidWinState(val).into()),
+        }
+    }
+}
+
+// Implement format! support
+impl fmt::Display for IcccmState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
+    }
+}
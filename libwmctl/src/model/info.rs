@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::{SupportedFeatures, WmCtlResult, WM};
+
 /// Info provides information about the window manager and its environment.
 pub struct Info {
     pub id: u32,
@@ -9,5 +11,35 @@ pub struct Info {
     pub work_area: (u32, u32),
     pub screen_size: (u32, u32),
     pub desktops: u32,
+    pub active_desktop: u32,
+    pub num_managed_windows: usize,
+    pub num_all_windows: usize,
     pub supported: HashMap<u32, String>,
 }
+
+impl Info {
+    /// Get the supported atoms grouped by category
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let features = libwmctl::info().unwrap().supported_grouped();
+    /// ```
+    pub fn supported_grouped(&self) -> SupportedFeatures {
+        SupportedFeatures::from(&self.supported)
+    }
+
+    /// Re-read the volatile fields i.e. the work area, desktop count, active desktop, compositing
+    /// and window counts leaving the window manager's name, id and supported atoms as is. This is
+    /// far cheaper than calling `info()` again e.g. on every tick of a status bar.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let mut info = libwmctl::info().unwrap();
+    /// info.refresh().unwrap();
+    /// ```
+    pub fn refresh(&mut self) -> WmCtlResult<()> {
+        WM().read().unwrap().refresh_info(self)
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{State, WmCtlError};
+use std::{convert, fmt};
+
+/// LayoutEntry provides the saved arrangement of a single window
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutEntry {
+    pub class: String,      // window class used to match the window on restore
+    pub role: String,       // window role used to tell apart windows of the same class
+    pub desktop: i32,       // desktop numbered from 1 or -1 for sticky windows
+    pub x: i32,             // visible left edge of the window including decorations
+    pub y: i32,             // visible top edge of the window including decorations
+    pub w: u32,             // width of the window as used when resizing
+    pub h: u32,             // height of the window as used when resizing
+    pub states: Vec<State>, // restorable states e.g. maximized or above
+}
+
+/// Layout provides a serializable snapshot of the arrangement of all managed windows such that it
+/// can be persisted and restored later. It serializes to one tab separated line per window via
+/// `to_string()` and is read back with `Layout::try_from`. Backslashes, tabs and line breaks in the
+/// class and role are escaped so that they can't break up the fields or lines.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Layout {
+    pub entries: Vec<LayoutEntry>,
+}
+
+impl Layout {
+    /// Match the saved entries against the given current windows. Windows are first matched by
+    /// class and role then any remaining entries are matched by class alone. Each window will be
+    /// matched at most once and in the order given.
+    ///
+    /// ### Arguments
+    /// * `windows` - (id, class, role) of the current windows
+    ///
+    /// ### Returns
+    /// * (entry index, window id) pairs
+    pub(crate) fn match_windows(&self, windows: &[(u32, String, String)]) -> Vec<(usize, u32)> {
+        let mut used = vec![false; windows.len()];
+        let mut matches: Vec<Option<u32>> = vec![None; self.entries.len()];
+
+        // Role is only unique within the application so it has to be paired with the class
+        for (i, entry) in self.entries.iter().enumerate().filter(|(_, x)| !x.role.is_empty()) {
+            if let Some(j) =
+                (0..windows.len()).find(|&j| !used[j] && windows[j].1 == entry.class && windows[j].2 == entry.role)
+            {
+                used[j] = true;
+                matches[i] = Some(windows[j].0);
+            }
+        }
+
+        // Fallback on the class for entries without a role or whose role didn't match
+        for (i, entry) in self.entries.iter().enumerate() {
+            if matches[i].is_some() || entry.class.is_empty() {
+                continue;
+            }
+            if let Some(j) = (0..windows.len()).find(|&j| !used[j] && windows[j].1 == entry.class) {
+                used[j] = true;
+                matches[i] = Some(windows[j].0);
+            }
+        }
+
+        matches.into_iter().enumerate().filter_map(|(i, x)| x.map(|id| (i, id))).collect()
+    }
+}
+
+// States the user arranged that can be reapplied on restore
+const RESTORABLE: [State; 9] = [
+    State::Above,
+    State::Below,
+    State::Fullscreen,
+    State::MaxHorz,
+    State::MaxVert,
+    State::Shaded,
+    State::SkipPager,
+    State::SkipTaskbar,
+    State::Sticky,
+];
+
+/// Check if the state is one that the user arranged and can be reapplied on restore
+pub(crate) fn is_restorable(state: &State) -> bool {
+    RESTORABLE.contains(state)
+}
+
+// Implement format! support
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for x in self.entries.iter() {
+            let states = x.states.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
+            let (class, role) = (escape(&x.class), escape(&x.role));
+            writeln!(f, "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}", class, role, x.desktop, x.x, x.y, x.w, x.h, states)?;
+        }
+        Ok(())
+    }
+}
+
+// Convert from &str to Layout
+impl convert::TryFrom<&str> for Layout {
+    type Error = WmCtlError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        let mut entries = vec![];
+        for line in val.lines().filter(|x| !x.trim().is_empty()) {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            if fields.len() != 8 {
+                return Err(WmCtlError::InvalidLayout(line.to_owned()));
+            }
+            let err = || WmCtlError::InvalidLayout(line.to_owned());
+            let mut states = vec![];
+            for name in fields[7].split(',').filter(|x| !x.is_empty()) {
+                states.push(parse_state(name).ok_or_else(err)?);
+            }
+            entries.push(LayoutEntry {
+                class: unescape(fields[0]).ok_or_else(err)?,
+                role: unescape(fields[1]).ok_or_else(err)?,
+                desktop: fields[2].parse().map_err(|_| err())?,
+                x: fields[3].parse().map_err(|_| err())?,
+                y: fields[4].parse().map_err(|_| err())?,
+                w: fields[5].parse().map_err(|_| err())?,
+                h: fields[6].parse().map_err(|_| err())?,
+                states,
+            });
+        }
+        Ok(Layout { entries })
+    }
+}
+
+// Convert from a String to a Layout
+impl convert::TryFrom<String> for Layout {
+    type Error = WmCtlError;
+
+    fn try_from(val: String) -> Result<Self, Self::Error> {
+        Layout::try_from(val.as_str())
+    }
+}
+
+/// Escape the backslashes, tabs and line breaks in the field
+fn escape(field: &str) -> String {
+    let mut escaped = String::with_capacity(field.len());
+    for c in field.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Reverse the escaping of the field returning None on an unknown or incomplete escape
+fn unescape(field: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        unescaped.push(match chars.next()? {
+            '\\' => '\\',
+            't' => '\t',
+            'n' => '\n',
+            'r' => '\r',
+            _ => return None,
+        });
+    }
+    Some(unescaped)
+}
+
+/// Parse the restorable state from its display name
+fn parse_state(name: &str) -> Option<State> {
+    RESTORABLE.into_iter().find(|x| x.to_string() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(class: &str, role: &str) -> LayoutEntry {
+        LayoutEntry {
+            class: class.to_owned(),
+            role: role.to_owned(),
+            desktop: 1,
+            x: 0,
+            y: 0,
+            w: 100,
+            h: 100,
+            states: vec![],
+        }
+    }
+
+    fn win(id: u32, class: &str, role: &str) -> (u32, String, String) {
+        (id, class.to_owned(), role.to_owned())
+    }
+
+    #[test]
+    fn test_match_windows() {
+        let layout = Layout {
+            entries: vec![
+                entry("firefox", ""),
+                entry("firefox", "browser"),
+                entry("gimp", "toolbox"),
+                entry("vlc", ""),
+            ],
+        };
+        let windows = vec![
+            win(1, "firefox", "browser"),
+            win(2, "firefox", "about"),
+            win(3, "gimp", "image"),
+        ];
+
+        // Role wins over order, class is the fallback and missing windows are skipped
+        assert_eq!(layout.match_windows(&windows), vec![(0, 2), (1, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn test_layout_round_trip() {
+        let mut first = entry("firefox", "browser");
+        first.desktop = -1;
+        first.x = -10;
+        first.states = vec![State::MaxVert, State::Above];
+        let layout = Layout {
+            entries: vec![first, entry("kitty", "")],
+        };
+        assert_eq!(Layout::try_from(layout.to_string()).unwrap(), layout);
+    }
+
+    #[test]
+    fn test_layout_round_trip_escaped() {
+        let layout = Layout {
+            entries: vec![entry("my\tapp", "role\nwith\\breaks\r"), entry("kitty", "")],
+        };
+        let text = layout.to_string();
+        assert_eq!(text.lines().count(), 2);
+        assert_eq!(text.lines().next().unwrap().split('\t').count(), 8);
+        assert_eq!(Layout::try_from(text).unwrap(), layout);
+
+        // Unknown and dangling escapes are rejected
+        assert!(Layout::try_from("fire\\xfox\t\t1\t0\t0\t100\t100\t").is_err());
+        assert!(Layout::try_from("firefox\\\t\t1\t0\t0\t100\t100\t").is_err());
+    }
+
+    #[test]
+    fn test_layout_invalid() {
+        assert!(Layout::try_from("firefox\t\t1\t0\t0\t100").is_err());
+        assert!(Layout::try_from("firefox\t\t1\t0\t0\t100\t100\tfocused").is_err());
+        assert!(Layout::try_from("firefox\t\tx\t0\t0\t100\t100\t").is_err());
+        assert_eq!(Layout::try_from("").unwrap(), Layout::default());
+    }
+}
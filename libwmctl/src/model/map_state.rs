@@ -30,8 +30,6 @@ impl MapState {
 // Implement format! support
 impl fmt::Display for MapState {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            _ => write!(f, "{}", format!("{:?}", self).to_lowercase()),
-        }
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
     }
 }
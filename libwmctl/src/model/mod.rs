@@ -4,24 +4,52 @@
 //! ```
 //! use libwmctl::prelude::*;
 //! ```
+mod action;
+mod edge;
+mod event;
+mod event_mask;
 mod gravity;
+mod icccm_state;
 mod info;
 mod kind;
+mod layout;
 mod map_state;
 mod position;
 mod property;
+mod screen;
+mod selector;
 mod shape;
+mod size_hints;
 mod state;
+mod strut;
+mod supported;
+mod window_row;
+mod wm_hints;
+mod workspace;
 
 // Export contents of modules
+pub use action::*;
+pub use edge::*;
+pub use event::*;
+pub use event_mask::*;
 pub use gravity::*;
+pub use icccm_state::*;
 pub use info::*;
 pub use kind::*;
+pub use layout::*;
 pub use map_state::*;
 pub use position::*;
 pub use property::*;
+pub use screen::*;
+pub use selector::*;
 pub use shape::*;
+pub use size_hints::*;
 pub use state::*;
+pub use strut::*;
+pub use supported::*;
+pub use window_row::*;
+pub use wm_hints::*;
+pub use workspace::*;
 
 // Define the second byte of the move resize flags 32bit value
 // Used to indicate that the associated value has been changed and needs to be acted upon
@@ -34,6 +62,35 @@ pub const MOVE_RESIZE_WINDOW_HEIGHT: MoveResizeWindowFlags = 1 << 11;
 pub type WindowStateAction = u32;
 pub const WINDOW_STATE_ACTION_REMOVE: WindowStateAction = 0;
 pub const WINDOW_STATE_ACTION_ADD: WindowStateAction = 1;
+pub const WINDOW_STATE_ACTION_TOGGLE: WindowStateAction = 2;
+
+// Desktop value indicating the window should appear on all desktops
+pub const DESKTOP_STICKY: u32 = 0xFFFFFFFF;
+
+// ICCCM WM_HINTS flags stored in the first value of the WM_HINTS property
+pub type WmHintsFlags = u32;
+pub const WM_HINTS_INPUT: WmHintsFlags = 1;
+pub const WM_HINTS_STATE: WmHintsFlags = 1 << 1;
+pub const WM_HINTS_ICON_PIXMAP: WmHintsFlags = 1 << 2;
+pub const WM_HINTS_ICON_WINDOW: WmHintsFlags = 1 << 3;
+pub const WM_HINTS_ICON_POSITION: WmHintsFlags = 1 << 4;
+pub const WM_HINTS_ICON_MASK: WmHintsFlags = 1 << 5;
+pub const WM_HINTS_WINDOW_GROUP: WmHintsFlags = 1 << 6;
+pub const WM_HINTS_URGENCY: WmHintsFlags = 1 << 8;
+
+// ICCCM WM_NORMAL_HINTS flags stored in the first value of the WM_NORMAL_HINTS property
+// Used to indicate which of the size hints were actually declared by the application
+pub type SizeHintsFlags = u32;
+pub const SIZE_HINTS_US_POSITION: SizeHintsFlags = 1;
+pub const SIZE_HINTS_US_SIZE: SizeHintsFlags = 1 << 1;
+pub const SIZE_HINTS_P_POSITION: SizeHintsFlags = 1 << 2;
+pub const SIZE_HINTS_P_SIZE: SizeHintsFlags = 1 << 3;
+pub const SIZE_HINTS_P_MIN_SIZE: SizeHintsFlags = 1 << 4;
+pub const SIZE_HINTS_P_MAX_SIZE: SizeHintsFlags = 1 << 5;
+pub const SIZE_HINTS_P_RESIZE_INC: SizeHintsFlags = 1 << 6;
+pub const SIZE_HINTS_P_ASPECT: SizeHintsFlags = 1 << 7;
+pub const SIZE_HINTS_P_BASE_SIZE: SizeHintsFlags = 1 << 8;
+pub const SIZE_HINTS_P_WIN_GRAVITY: SizeHintsFlags = 1 << 9;
 
 /// Border provides a simple way to store border values
 #[derive(Default)]
@@ -77,3 +134,77 @@ impl Rect {
         Self { w, h }
     }
 }
+
+/// Geometry provides a simple way to store the position and size of a window
+/// * Serializable with the `serde` feature enabled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Geometry {
+    pub x: i32,
+    pub y: i32,
+    pub w: u32,
+    pub h: u32,
+}
+
+impl Geometry {
+    pub fn new(x: i32, y: i32, w: u32, h: u32) -> Self {
+        Self { x, y, w, h }
+    }
+
+    /// Get the area shared by the two rectangles. Rectangles that only touch along an edge or
+    /// corner don't intersect.
+    ///
+    /// ### Arguments
+    /// * `other` - rectangle to intersect with
+    pub fn intersection(&self, other: &Geometry) -> Option<Geometry> {
+        let l = (self.x as i64).max(other.x as i64);
+        let t = (self.y as i64).max(other.y as i64);
+        let r = (self.x as i64 + self.w as i64).min(other.x as i64 + other.w as i64);
+        let b = (self.y as i64 + self.h as i64).min(other.y as i64 + other.h as i64);
+        if r > l && b > t {
+            Some(Geometry::new(l as i32, t as i32, (r - l) as u32, (b - t) as u32))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Geometry {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Geometry", 4)?;
+        state.serialize_field("x", &self.x)?;
+        state.serialize_field("y", &self.y)?;
+        state.serialize_field("w", &self.w)?;
+        state.serialize_field("h", &self.h)?;
+        state.end()
+    }
+}
+
+impl From<(i32, i32, u32, u32)> for Geometry {
+    fn from(val: (i32, i32, u32, u32)) -> Self {
+        Self::new(val.0, val.1, val.2, val.3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometry_intersection() {
+        let a = Geometry::new(0, 0, 100, 100);
+        assert_eq!(a.intersection(&Geometry::new(50, 50, 100, 100)), Some(Geometry::new(50, 50, 50, 50)));
+        assert_eq!(a.intersection(&Geometry::new(-50, 20, 100, 10)), Some(Geometry::new(0, 20, 50, 10)));
+
+        // Containment yields the inner rectangle
+        assert_eq!(a.intersection(&Geometry::new(10, 10, 20, 20)), Some(Geometry::new(10, 10, 20, 20)));
+        assert_eq!(Geometry::new(10, 10, 20, 20).intersection(&a), Some(Geometry::new(10, 10, 20, 20)));
+
+        // Touching edges and corners or empty rectangles don't intersect
+        assert_eq!(a.intersection(&Geometry::new(100, 0, 100, 100)), None);
+        assert_eq!(a.intersection(&Geometry::new(100, 100, 10, 10)), None);
+        assert_eq!(a.intersection(&Geometry::new(50, 50, 0, 0)), None);
+        assert_eq!(a.intersection(&Geometry::new(500, 500, 10, 10)), None);
+    }
+}
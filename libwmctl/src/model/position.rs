@@ -24,9 +24,7 @@ pub enum Position {
 // Implement format! support
 impl fmt::Display for Position {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            _ => write!(f, "{}", format!("{:?}", self).to_lowercase()),
-        }
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
     }
 }
 
@@ -49,7 +47,7 @@ impl convert::TryFrom<&str> for Position {
             "right-center" => Ok(Position::RightCenter),
             "top-center" => Ok(Position::TopCenter),
             "bottom-center" => Ok(Position::BottomCenter),
-            _ => Err(WmCtlError::InvalidWinPosition(val.to_string()).into()),
+            _ => Err(WmCtlError::InvalidWinPosition(val.to_string())),
         }
     }
 }
@@ -0,0 +1,290 @@
+use crate::{Geometry, Strut, Window, WmCtlResult};
+
+/// Monitor provides the name, primary flag and area of a single physical or logical monitor as
+/// reported by RandR. Positions are relative to the top left of the combined X11 screen.
+/// * Serializable with the `serde` feature enabled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monitor {
+    pub name: String,         // monitor name e.g. DP-1 or user defined
+    pub primary: bool,        // primary monitor as configured by the user
+    pub x: i32,               // left edge of the monitor on the combined screen
+    pub y: i32,               // top edge of the monitor on the combined screen
+    pub w: u32,               // width of the monitor
+    pub h: u32,               // height of the monitor
+    pub work_area: Geometry,  // area of the monitor not reserved by dock struts
+    pub outputs: Vec<String>, // names of the RandR outputs driving the monitor e.g. HDMI-1
+}
+
+impl Monitor {
+    /// Create a new monitor with a work area covering the entire monitor and no known outputs
+    ///
+    /// ### Arguments
+    /// * `name` - monitor name e.g. DP-1 or user defined
+    /// * `primary` - primary monitor as configured by the user
+    /// * `x` - left edge of the monitor on the combined screen
+    /// * `y` - top edge of the monitor on the combined screen
+    /// * `w` - width of the monitor
+    /// * `h` - height of the monitor
+    pub fn new(name: &str, primary: bool, x: i32, y: i32, w: u32, h: u32) -> Self {
+        Self {
+            name: name.to_owned(),
+            primary,
+            x,
+            y,
+            w,
+            h,
+            work_area: Geometry::new(x, y, w, h),
+            outputs: vec![],
+        }
+    }
+
+    /// Check if the monitor is the one with the given name or is driven by the output with the
+    /// given name
+    ///
+    /// ### Arguments
+    /// * `name` - monitor or RandR output name e.g. HDMI-1
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name == name || self.outputs.iter().any(|x| x == name)
+    }
+
+    /// Shrink the work area by the space the given strut reserves on this monitor. Struts are
+    /// relative to the edges of the combined screen so e.g. a right strut only reaches the
+    /// rightmost monitor unless it is wider than that monitor.
+    ///
+    /// ### Arguments
+    /// * `strut` - space reserved by a dock or panel
+    /// * `w` - width of the combined screen
+    /// * `h` - height of the combined screen
+    pub fn reserve(&mut self, strut: &Strut, w: u32, h: u32) {
+        let (mx, my) = (self.x as i64, self.y as i64);
+        let (mr, mb) = (mx + self.w as i64, my + self.h as i64);
+        let overlaps = |start: u32, end: u32, lo: i64, hi: i64| (start as i64) < hi && (end as i64) >= lo;
+
+        let area = &self.work_area;
+        let (mut l, mut t) = (area.x as i64, area.y as i64);
+        let (mut r, mut b) = (l + area.w as i64, t + area.h as i64);
+        if strut.left > 0 && overlaps(strut.left_start_y, strut.left_end_y, my, mb) {
+            l = l.max((strut.left as i64).min(mr));
+        }
+        if strut.right > 0 && overlaps(strut.right_start_y, strut.right_end_y, my, mb) {
+            r = r.min((w as i64 - strut.right as i64).max(mx));
+        }
+        if strut.top > 0 && overlaps(strut.top_start_x, strut.top_end_x, mx, mr) {
+            t = t.max((strut.top as i64).min(mb));
+        }
+        if strut.bottom > 0 && overlaps(strut.bottom_start_x, strut.bottom_end_x, mx, mr) {
+            b = b.min((h as i64 - strut.bottom as i64).max(my));
+        }
+        self.work_area = Geometry::new(l as i32, t as i32, (r - l).max(0) as u32, (b - t).max(0) as u32);
+    }
+
+    /// Check if the given screen coordinate lies on the monitor
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        let (x, y) = (x as i64, y as i64);
+        let (mx, my) = (self.x as i64, self.y as i64);
+        x >= mx && x < mx + self.w as i64 && y >= my && y < my + self.h as i64
+    }
+
+    /// Get the area the given rectangle overlaps with the monitor
+    pub fn overlap(&self, geometry: &Geometry) -> u64 {
+        let monitor = Geometry::new(self.x, self.y, self.w, self.h);
+        monitor.intersection(geometry).map_or(0, |x| x.w as u64 * x.h as u64)
+    }
+}
+
+/// ScreenLayout provides the combined bounding box of all monitors along with the monitors
+/// themselves so that layout code has a single place to answer "which monitor" questions.
+/// * Serializable with the `serde` feature enabled
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScreenLayout {
+    pub x: i32,                 // left edge of the bounding box of all monitors
+    pub y: i32,                 // top edge of the bounding box of all monitors
+    pub w: u32,                 // width of the bounding box of all monitors
+    pub h: u32,                 // height of the bounding box of all monitors
+    pub monitors: Vec<Monitor>, // monitors in the order RandR reported them
+}
+
+impl ScreenLayout {
+    /// Create a new screen layout computing the bounding box of the given monitors
+    ///
+    /// ### Arguments
+    /// * `monitors` - monitors making up the screen
+    pub fn new(monitors: Vec<Monitor>) -> Self {
+        if monitors.is_empty() {
+            return Self::default();
+        }
+        let l = monitors.iter().map(|m| m.x as i64).min().unwrap_or_default();
+        let t = monitors.iter().map(|m| m.y as i64).min().unwrap_or_default();
+        let r = monitors.iter().map(|m| m.x as i64 + m.w as i64).max().unwrap_or_default();
+        let b = monitors.iter().map(|m| m.y as i64 + m.h as i64).max().unwrap_or_default();
+        Self {
+            x: l as i32,
+            y: t as i32,
+            w: (r - l) as u32,
+            h: (b - t) as u32,
+            monitors,
+        }
+    }
+
+    /// Get the primary monitor falling back on the first monitor if none is marked primary
+    pub fn primary(&self) -> Option<&Monitor> {
+        self.monitors.iter().find(|m| m.primary).or_else(|| self.monitors.first())
+    }
+
+    /// Get the monitor containing the given screen coordinate
+    ///
+    /// ### Arguments
+    /// * `x` - x coordinate on the combined screen
+    /// * `y` - y coordinate on the combined screen
+    pub fn monitor_containing(&self, x: i32, y: i32) -> Option<&Monitor> {
+        self.monitors.iter().find(|m| m.contains(x, y))
+    }
+
+    /// Get the monitor the given geometry overlaps the most with. Ties go to the monitor listed
+    /// first and a geometry off all monitors returns None.
+    ///
+    /// ### Arguments
+    /// * `geometry` - area to find the monitor for
+    pub fn monitor_overlapping(&self, geometry: &Geometry) -> Option<&Monitor> {
+        let mut best: Option<(&Monitor, u64)> = None;
+        for monitor in self.monitors.iter() {
+            let area = monitor.overlap(geometry);
+            if area > 0 && best.is_none_or(|(_, x)| area > x) {
+                best = Some((monitor, area));
+            }
+        }
+        best.map(|(monitor, _)| monitor)
+    }
+
+    /// Get the monitor the given window is on i.e. the one showing the most of the window
+    ///
+    /// ### Arguments
+    /// * `win` - window to find the monitor for
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let layout = libwmctl::screen_layout().unwrap();
+    /// let monitor = layout.monitor_of_window(&libwmctl::active()).unwrap();
+    /// ```
+    pub fn monitor_of_window(&self, win: &Window) -> WmCtlResult<Option<&Monitor>> {
+        Ok(self.monitor_overlapping(&Geometry::from(win.geometry()?)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Monitor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Monitor", 8)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("primary", &self.primary)?;
+        state.serialize_field("x", &self.x)?;
+        state.serialize_field("y", &self.y)?;
+        state.serialize_field("w", &self.w)?;
+        state.serialize_field("h", &self.h)?;
+        state.serialize_field("work_area", &self.work_area)?;
+        state.serialize_field("outputs", &self.outputs)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ScreenLayout {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("ScreenLayout", 5)?;
+        state.serialize_field("x", &self.x)?;
+        state.serialize_field("y", &self.y)?;
+        state.serialize_field("w", &self.w)?;
+        state.serialize_field("h", &self.h)?;
+        state.serialize_field("monitors", &self.monitors)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout() -> ScreenLayout {
+        ScreenLayout::new(vec![
+            Monitor::new("DP-1", false, 0, 200, 1920, 1080),
+            Monitor::new("DP-2", true, 1920, 0, 2560, 1440),
+        ])
+    }
+
+    #[test]
+    fn test_screen_layout_bounds() {
+        let layout = layout();
+        assert_eq!((layout.x, layout.y, layout.w, layout.h), (0, 0, 4480, 1440));
+        assert_eq!(layout.primary().unwrap().name, "DP-2");
+        assert_eq!(ScreenLayout::new(vec![]), ScreenLayout::default());
+    }
+
+    #[test]
+    fn test_monitor_containing() {
+        let layout = layout();
+        assert_eq!(layout.monitor_containing(0, 200).unwrap().name, "DP-1");
+        assert_eq!(layout.monitor_containing(1919, 1279).unwrap().name, "DP-1");
+        assert_eq!(layout.monitor_containing(1920, 0).unwrap().name, "DP-2");
+
+        // Gap above the shorter monitor and past the right edge
+        assert!(layout.monitor_containing(100, 100).is_none());
+        assert!(layout.monitor_containing(4480, 0).is_none());
+    }
+
+    #[test]
+    fn test_monitor_overlapping() {
+        let layout = layout();
+        let geometry = Geometry::new(1800, 300, 400, 400);
+        assert_eq!(layout.monitor_overlapping(&geometry).unwrap().name, "DP-2");
+        let geometry = Geometry::new(1700, 300, 400, 400);
+        assert_eq!(layout.monitor_overlapping(&geometry).unwrap().name, "DP-1");
+        assert!(layout.monitor_overlapping(&Geometry::new(-500, 0, 100, 100)).is_none());
+    }
+
+    #[test]
+    fn test_monitor_is_named() {
+        let mut monitor = Monitor::new("desk", false, 0, 0, 3840, 1080);
+        monitor.outputs = vec!["HDMI-1".to_owned(), "DP-2".to_owned()];
+        assert!(monitor.is_named("desk"));
+        assert!(monitor.is_named("HDMI-1"));
+        assert!(monitor.is_named("DP-2"));
+        assert!(!monitor.is_named("DP-1"));
+    }
+
+    #[test]
+    fn test_monitor_reserve() {
+        let (w, h) = (4480, 1440);
+        let mut monitors = layout().monitors;
+        let (left, right) = monitors.split_at_mut(1);
+        let (left, right) = (&mut left[0], &mut right[0]);
+
+        // A top bar across the right monitor only
+        let bar = Strut {
+            top: 30,
+            top_start_x: 1920,
+            top_end_x: 4479,
+            ..Default::default()
+        };
+        left.reserve(&bar, w, h);
+        right.reserve(&bar, w, h);
+        assert_eq!(left.work_area, Geometry::new(0, 200, 1920, 1080));
+        assert_eq!(right.work_area, Geometry::new(1920, 30, 2560, 1410));
+
+        // A legacy bottom strut spans the whole screen but only reaches the taller monitor
+        let dock = Strut::from_full(&[0, 0, 0, 48], w, h).unwrap();
+        left.reserve(&dock, w, h);
+        right.reserve(&dock, w, h);
+        assert_eq!(left.work_area, Geometry::new(0, 200, 1920, 1080));
+        assert_eq!(right.work_area, Geometry::new(1920, 30, 2560, 1362));
+
+        // Left and right panels only reserve space on the outer monitors
+        let panel = Strut::from_full(&[64, 100, 0, 0], w, h).unwrap();
+        left.reserve(&panel, w, h);
+        right.reserve(&panel, w, h);
+        assert_eq!(left.work_area, Geometry::new(64, 200, 1856, 1080));
+        assert_eq!(right.work_area, Geometry::new(1920, 30, 2460, 1362));
+    }
+}
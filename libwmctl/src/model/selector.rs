@@ -0,0 +1,102 @@
+use crate::WmCtlError;
+use std::{convert, fmt};
+
+/// Selector provides a single consistent way to address windows e.g. by id, class or the active
+/// window. Parses from the `kind:value` form e.g. `class:firefox`, `pid:1234` or `active` with a
+/// bare decimal or `0x` prefixed hex number being treated as a window id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selector {
+    ById(u32),
+    ByClass(String),
+    ByName(String),
+    ByPid(i32),
+    ByRole(String),
+    Active,
+}
+
+// Implement format! support
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Selector::ById(x) => write!(f, "id:{}", x),
+            Selector::ByClass(x) => write!(f, "class:{}", x),
+            Selector::ByName(x) => write!(f, "name:{}", x),
+            Selector::ByPid(x) => write!(f, "pid:{}", x),
+            Selector::ByRole(x) => write!(f, "role:{}", x),
+            Selector::Active => write!(f, "active"),
+        }
+    }
+}
+
+// Convert from &str to Selector
+impl convert::TryFrom<&str> for Selector {
+    type Error = WmCtlError;
+
+    fn try_from(val: &str) -> Result<Self, Self::Error> {
+        let err = || WmCtlError::InvalidSelector(val.to_string());
+        if val.eq_ignore_ascii_case("active") {
+            return Ok(Selector::Active);
+        }
+        let (kind, value) = match val.split_once(':') {
+            Some((kind, value)) => (kind.to_lowercase(), value),
+            None => return parse_id(val).map(Selector::ById).ok_or_else(err),
+        };
+        match kind.as_ref() {
+            "id" => parse_id(value).map(Selector::ById).ok_or_else(err),
+            "class" => Ok(Selector::ByClass(value.to_string())),
+            "name" => Ok(Selector::ByName(value.to_string())),
+            "pid" => value.parse().map(Selector::ByPid).map_err(|_| err()),
+            "role" => Ok(Selector::ByRole(value.to_string())),
+            _ => Err(err()),
+        }
+    }
+}
+
+// Convert from a String to a Selector
+impl convert::TryFrom<String> for Selector {
+    type Error = WmCtlError;
+
+    fn try_from(val: String) -> Result<Self, Self::Error> {
+        Selector::try_from(val.as_str())
+    }
+}
+
+/// Parse the window id from either decimal or `0x` prefixed hex as reported by tools like xwininfo
+fn parse_id(val: &str) -> Option<u32> {
+    match val.strip_prefix("0x").or_else(|| val.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => val.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selector_parse() {
+        assert_eq!(Selector::try_from("active").unwrap(), Selector::Active);
+        assert_eq!(Selector::try_from("12345").unwrap(), Selector::ById(12345));
+        assert_eq!(Selector::try_from("0x3a00007").unwrap(), Selector::ById(0x3a00007));
+        assert_eq!(Selector::try_from("id:0x10").unwrap(), Selector::ById(16));
+        assert_eq!(Selector::try_from("Class:firefox").unwrap(), Selector::ByClass("firefox".to_owned()));
+        assert_eq!(Selector::try_from("name:a: b").unwrap(), Selector::ByName("a: b".to_owned()));
+        assert_eq!(Selector::try_from("pid:42").unwrap(), Selector::ByPid(42));
+        assert_eq!(Selector::try_from("role:browser").unwrap(), Selector::ByRole("browser".to_owned()));
+        assert!(Selector::try_from("pid:x").is_err());
+        assert!(Selector::try_from("firefox").is_err());
+        assert!(Selector::try_from("desktop:1").is_err());
+    }
+
+    #[test]
+    fn test_selector_round_trip() {
+        for x in [
+            Selector::ById(7),
+            Selector::ByClass("kitty".to_owned()),
+            Selector::ByPid(-1),
+            Selector::Active,
+        ] {
+            assert_eq!(Selector::try_from(x.to_string()).unwrap(), x);
+        }
+    }
+}
@@ -21,9 +21,7 @@ pub enum Shape {
 // Implement format! support
 impl fmt::Display for Shape {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            _ => write!(f, "{}", format!("{:?}", self).to_lowercase()),
-        }
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
     }
 }
 
@@ -42,7 +40,7 @@ impl convert::TryFrom<&str> for Shape {
             "large" => Ok(Shape::Large),
             "shrink" => Ok(Shape::Shrink),
             "unmax" => Ok(Shape::UnMax),
-            _ => Err(WmCtlError::InvalidWinShape(val.to_string()).into()),
+            _ => Err(WmCtlError::InvalidWinShape(val.to_string())),
         }
     }
 }
@@ -0,0 +1,170 @@
+use crate::model::*;
+
+/// SizeHints provides the application's ICCCM WM_NORMAL_HINTS sizing constraints. Values are
+/// reported as stored by the application and the raw `flags` indicate which were declared, as an
+/// undeclared hint is stored as zero just like a hint declared as zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SizeHints {
+    pub flags: SizeHintsFlags,  // bit mask of the declared hints
+    pub min_width: u32,         // minimum width the window should be
+    pub min_height: u32,        // minimum height the window should be
+    pub max_width: u32,         // maximum width the window should be
+    pub max_height: u32,        // maximum height the window should be
+    pub width_inc: u32,         // width increments the window should be resized in
+    pub height_inc: u32,        // height increments the window should be resized in
+    pub min_aspect: (u32, u32), // minimum aspect ratio as numerator, denominator
+    pub max_aspect: (u32, u32), // maximum aspect ratio as numerator, denominator
+    pub base_width: u32,        // base width that resize increments are added to
+    pub base_height: u32,       // base height that resize increments are added to
+    pub win_gravity: u32,       // gravity to use when the window is moved or resized
+}
+
+impl SizeHints {
+    /// Create the size hints from the WM_NORMAL_HINTS values. Older applications only store the
+    /// first 15 values so missing values are treated as zero.
+    ///
+    /// ### Arguments
+    /// * `values` - flags, pad[4], min_width, min_height, max_width, max_height, width_inc,
+    ///   height_inc, min_aspect[2], max_aspect[2], base_width, base_height, win_gravity
+    pub fn from(values: &[u32]) -> SizeHints {
+        let v = |i: usize| values.get(i).copied().unwrap_or_default();
+        SizeHints {
+            flags: v(0),
+            min_width: v(5),
+            min_height: v(6),
+            max_width: v(7),
+            max_height: v(8),
+            width_inc: v(9),
+            height_inc: v(10),
+            min_aspect: (v(11), v(12)),
+            max_aspect: (v(13), v(14)),
+            base_width: v(15),
+            base_height: v(16),
+            win_gravity: v(17),
+        }
+    }
+
+    /// Check if the application declared a minimum size
+    pub fn has_min_size(&self) -> bool {
+        self.flags & SIZE_HINTS_P_MIN_SIZE != 0
+    }
+
+    /// Check if the application declared a maximum size
+    pub fn has_max_size(&self) -> bool {
+        self.flags & SIZE_HINTS_P_MAX_SIZE != 0
+    }
+
+    /// Check if the application declared resize increments
+    pub fn has_resize_inc(&self) -> bool {
+        self.flags & SIZE_HINTS_P_RESIZE_INC != 0
+    }
+
+    /// Check if the application declared aspect ratios
+    pub fn has_aspect(&self) -> bool {
+        self.flags & SIZE_HINTS_P_ASPECT != 0
+    }
+
+    /// Check if the application declared a base size
+    pub fn has_base_size(&self) -> bool {
+        self.flags & SIZE_HINTS_P_BASE_SIZE != 0
+    }
+
+    /// Check if the application declared a window gravity
+    pub fn has_win_gravity(&self) -> bool {
+        self.flags & SIZE_HINTS_P_WIN_GRAVITY != 0
+    }
+
+    /// Constrain the given size to one the window manager will accept i.e. within the min and max
+    /// size and a whole number of resize increments past the base size. As per ICCCM the base size
+    /// falls back on the min size and vice versa when only one of them is declared.
+    ///
+    /// ### Arguments
+    /// * `w` - width to constrain
+    /// * `h` - height to constrain
+    pub fn constrain(&self, w: u32, h: u32) -> (u32, u32) {
+        let (min_w, min_h) = if self.has_min_size() {
+            (self.min_width, self.min_height)
+        } else if self.has_base_size() {
+            (self.base_width, self.base_height)
+        } else {
+            (1, 1)
+        };
+        let (base_w, base_h) = if self.has_base_size() {
+            (self.base_width, self.base_height)
+        } else if self.has_min_size() {
+            (self.min_width, self.min_height)
+        } else {
+            (0, 0)
+        };
+
+        // A max of zero is treated as unconstrained
+        let max = |x: u32| if self.has_max_size() && x > 0 { x } else { u32::MAX };
+        let (max_w, max_h) = (max(self.max_width), max(self.max_height));
+        let (inc_w, inc_h) = if self.has_resize_inc() { (self.width_inc, self.height_inc) } else { (1, 1) };
+
+        let axis = |x: u32, min: u32, max: u32, base: u32, inc: u32| {
+            let min = min.max(1);
+            let mut x = x.clamp(min, max.max(min));
+            if inc > 1 && x > base {
+                x = base + (x - base) / inc * inc;
+                if x < min {
+                    x += inc;
+                }
+            }
+            x
+        };
+        (axis(w, min_w, max_w, base_w, inc_w), axis(h, min_h, max_h, base_h, inc_h))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_hints() {
+        // Min size and resize increments declared with a min width of zero
+        let flags = SIZE_HINTS_P_MIN_SIZE | SIZE_HINTS_P_RESIZE_INC;
+        let values = [flags, 0, 0, 0, 0, 0, 200, 0, 0, 10, 20, 0, 0, 0, 0, 0, 0, 0];
+        let hints = SizeHints::from(&values);
+        assert!(hints.has_min_size());
+        assert!(hints.has_resize_inc());
+        assert!(!hints.has_max_size());
+        assert_eq!((hints.min_width, hints.min_height), (0, 200));
+        assert_eq!((hints.width_inc, hints.height_inc), (10, 20));
+
+        // Older 15 value layout without base size and gravity
+        let hints = SizeHints::from(&values[..15]);
+        assert_eq!(hints.base_width, 0);
+        assert_eq!(hints.win_gravity, 0);
+        assert_eq!(hints.flags, flags);
+    }
+
+    #[test]
+    fn test_size_hints_constrain() {
+        // Nothing declared only keeps the size non zero
+        let hints = SizeHints::default();
+        assert_eq!(hints.constrain(801, 0), (801, 1));
+
+        // Terminal style increments past the base size
+        let mut hints = SizeHints {
+            flags: SIZE_HINTS_P_BASE_SIZE | SIZE_HINTS_P_RESIZE_INC,
+            base_width: 4,
+            base_height: 2,
+            width_inc: 10,
+            height_inc: 20,
+            ..Default::default()
+        };
+        assert_eq!(hints.constrain(808, 611), (804, 602));
+        assert_eq!(hints.constrain(2, 1), (4, 2));
+
+        // Min and max sizes with a zero max being unconstrained
+        hints.flags |= SIZE_HINTS_P_MIN_SIZE | SIZE_HINTS_P_MAX_SIZE;
+        hints.min_width = 100;
+        hints.min_height = 50;
+        hints.max_width = 500;
+        hints.max_height = 0;
+        assert_eq!(hints.constrain(50, 5000), (104, 4982));
+        assert_eq!(hints.constrain(900, 10), (494, 62));
+    }
+}
@@ -54,6 +54,26 @@ impl State {
             Err(WmCtlError::InvalidWinState(val).into())
         }
     }
+
+    /// Convert the State back into its atom value
+    pub fn atom(&self, atoms: &AtomCollection) -> WmCtlResult<u32> {
+        Ok(match self {
+            State::Above => atoms._NET_WM_STATE_ABOVE,
+            State::Below => atoms._NET_WM_STATE_BELOW,
+            State::DemandsAttention => atoms._NET_WM_STATE_DEMANDS_ATTENTION,
+            State::Focused => atoms._NET_WM_STATE_FOCUSED,
+            State::Fullscreen => atoms._NET_WM_STATE_FULLSCREEN,
+            State::Hidden => atoms._NET_WM_STATE_HIDDEN,
+            State::MaxHorz => atoms._NET_WM_STATE_MAXIMIZED_HORZ,
+            State::MaxVert => atoms._NET_WM_STATE_MAXIMIZED_VERT,
+            State::Modal => atoms._NET_WM_STATE_MODAL,
+            State::Shaded => atoms._NET_WM_STATE_SHADED,
+            State::SkipPager => atoms._NET_WM_STATE_SKIP_PAGER,
+            State::SkipTaskbar => atoms._NET_WM_STATE_SKIP_TASKBAR,
+            State::Sticky => atoms._NET_WM_STATE_STICKY,
+            State::Invalid => return Err(WmCtlError::InvalidWinState(0).into()),
+        })
+    }
 }
 
 // Implement format! support
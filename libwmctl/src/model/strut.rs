@@ -0,0 +1,138 @@
+use crate::{WmCtlError, WmCtlResult};
+
+/// Strut provides the space a panel or dock window reserves along the edges of the screen as
+/// defined by _NET_WM_STRUT_PARTIAL. Each edge reserves a thickness in pixels over a range along
+/// that edge so that e.g. a bar can reserve space on only part of the top edge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Strut {
+    pub left: u32,           // reserved width along the left edge
+    pub right: u32,          // reserved width along the right edge
+    pub top: u32,            // reserved height along the top edge
+    pub bottom: u32,         // reserved height along the bottom edge
+    pub left_start_y: u32,   // start of the reserved range on the left edge
+    pub left_end_y: u32,     // end of the reserved range on the left edge
+    pub right_start_y: u32,  // start of the reserved range on the right edge
+    pub right_end_y: u32,    // end of the reserved range on the right edge
+    pub top_start_x: u32,    // start of the reserved range on the top edge
+    pub top_end_x: u32,      // end of the reserved range on the top edge
+    pub bottom_start_x: u32, // start of the reserved range on the bottom edge
+    pub bottom_end_x: u32,   // end of the reserved range on the bottom edge
+}
+
+impl Strut {
+    /// Create a strut from the 12 _NET_WM_STRUT_PARTIAL values
+    ///
+    /// ### Arguments
+    /// * `values` - left, right, top, bottom, left_start_y, left_end_y, right_start_y,
+    ///   right_end_y, top_start_x, top_end_x, bottom_start_x, bottom_end_x
+    pub fn from(values: &[u32]) -> WmCtlResult<Strut> {
+        if values.len() != 12 {
+            return Err(WmCtlError::InvalidStrut(format!("expected 12 values got {}", values.len())).into());
+        }
+        let strut = Strut {
+            left: values[0],
+            right: values[1],
+            top: values[2],
+            bottom: values[3],
+            left_start_y: values[4],
+            left_end_y: values[5],
+            right_start_y: values[6],
+            right_end_y: values[7],
+            top_start_x: values[8],
+            top_end_x: values[9],
+            bottom_start_x: values[10],
+            bottom_end_x: values[11],
+        };
+        strut.validate()?;
+        Ok(strut)
+    }
+
+    /// Create a strut from the 4 legacy _NET_WM_STRUT values which always reserve the entire edge
+    /// of the screen
+    ///
+    /// ### Arguments
+    /// * `values` - left, right, top, bottom
+    /// * `w` - width of the screen
+    /// * `h` - height of the screen
+    pub fn from_full(values: &[u32], w: u32, h: u32) -> WmCtlResult<Strut> {
+        if values.len() != 4 {
+            return Err(WmCtlError::InvalidStrut(format!("expected 4 values got {}", values.len())).into());
+        }
+        let (y, x) = (h.saturating_sub(1), w.saturating_sub(1));
+        Strut::from(&[values[0], values[1], values[2], values[3], 0, y, 0, y, 0, x, 0, x])
+    }
+
+    /// Convert the strut into the 12 _NET_WM_STRUT_PARTIAL values
+    pub fn values(&self) -> [u32; 12] {
+        [
+            self.left,
+            self.right,
+            self.top,
+            self.bottom,
+            self.left_start_y,
+            self.left_end_y,
+            self.right_start_y,
+            self.right_end_y,
+            self.top_start_x,
+            self.top_end_x,
+            self.bottom_start_x,
+            self.bottom_end_x,
+        ]
+    }
+
+    /// Validate that each edge reserving space has a range that doesn't end before it starts
+    pub fn validate(&self) -> WmCtlResult<()> {
+        let edges = [
+            ("left", self.left, self.left_start_y, self.left_end_y),
+            ("right", self.right, self.right_start_y, self.right_end_y),
+            ("top", self.top, self.top_start_x, self.top_end_x),
+            ("bottom", self.bottom, self.bottom_start_x, self.bottom_end_x),
+        ];
+        for (name, size, start, end) in edges {
+            if size > 0 && end < start {
+                return Err(WmCtlError::InvalidStrut(format!("{} range ends before it starts", name)).into());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strut() {
+        // Top bar across the first 1920 pixels
+        let values = [0, 0, 30, 0, 0, 0, 0, 0, 0, 1919, 0, 0];
+        let strut = Strut::from(&values).unwrap();
+        assert_eq!(strut.top, 30);
+        assert_eq!(strut.top_end_x, 1919);
+        assert_eq!(strut.values(), values);
+
+        // Wrong number of values
+        assert!(Strut::from(&values[..4]).is_err());
+
+        // Legacy struts span the whole edge
+        let strut = Strut::from_full(&[0, 0, 30, 0], 1920, 1080).unwrap();
+        assert_eq!(strut.values(), [0, 0, 30, 0, 0, 1079, 0, 1079, 0, 1919, 0, 1919]);
+        assert!(Strut::from_full(&values, 1920, 1080).is_err());
+
+        // Range ending before it starts
+        let strut = Strut {
+            left: 30,
+            left_start_y: 100,
+            left_end_y: 50,
+            ..Default::default()
+        };
+        assert!(strut.validate().is_err());
+
+        // Ranges are ignored for edges that don't reserve space
+        let strut = Strut {
+            left_start_y: 100,
+            left_end_y: 50,
+            ..Default::default()
+        };
+        assert!(strut.validate().is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+// Root window properties defined by the EWMH spec
+const ROOT_PROPERTIES: [&str; 14] = [
+    "_NET_SUPPORTED",
+    "_NET_CLIENT_LIST",
+    "_NET_CLIENT_LIST_STACKING",
+    "_NET_NUMBER_OF_DESKTOPS",
+    "_NET_DESKTOP_GEOMETRY",
+    "_NET_DESKTOP_VIEWPORT",
+    "_NET_CURRENT_DESKTOP",
+    "_NET_DESKTOP_NAMES",
+    "_NET_ACTIVE_WINDOW",
+    "_NET_WORKAREA",
+    "_NET_SUPPORTING_WM_CHECK",
+    "_NET_VIRTUAL_ROOTS",
+    "_NET_DESKTOP_LAYOUT",
+    "_NET_SHOWING_DESKTOP",
+];
+
+// Window manager protocols defined by the EWMH spec
+const PROTOCOLS: [&str; 3] = ["_NET_WM_PING", "_NET_WM_SYNC_REQUEST", "_NET_WM_FULLSCREEN_MONITORS"];
+
+/// SupportedFeatures provides the window manager's supported atoms grouped by category along with
+/// quick checks for commonly used features.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SupportedFeatures {
+    pub root_properties: Vec<String>, // root window properties e.g. _NET_DESKTOP_NAMES
+    pub window_states: Vec<String>,   // window states e.g. _NET_WM_STATE_FULLSCREEN
+    pub actions: Vec<String>,         // allowed actions e.g. _NET_WM_ACTION_MOVE
+    pub protocols: Vec<String>,       // window manager protocols e.g. _NET_WM_PING
+    pub other: Vec<String>,           // remaining window properties and client messages
+
+    pub fullscreen: bool,
+    pub sticky: bool,
+    pub maximize: bool,
+    pub demands_attention: bool,
+    pub desktop_names: bool,
+    pub desktop_viewport: bool,
+    pub close_window: bool,
+    pub moveresize_window: bool,
+}
+
+impl SupportedFeatures {
+    /// Group the given supported atoms by category
+    ///
+    /// ### Arguments
+    /// * `supported` - supported atom ids and names as reported by the window manager
+    pub fn from(supported: &HashMap<u32, String>) -> Self {
+        let mut features = SupportedFeatures::default();
+        let mut names = supported.values().cloned().collect::<Vec<_>>();
+        names.sort();
+
+        let has = |name: &str| names.iter().any(|x| x == name);
+        features.fullscreen = has("_NET_WM_STATE_FULLSCREEN");
+        features.sticky = has("_NET_WM_STATE_STICKY");
+        features.maximize = has("_NET_WM_STATE_MAXIMIZED_HORZ") && has("_NET_WM_STATE_MAXIMIZED_VERT");
+        features.demands_attention = has("_NET_WM_STATE_DEMANDS_ATTENTION");
+        features.desktop_names = has("_NET_DESKTOP_NAMES");
+        features.desktop_viewport = has("_NET_DESKTOP_VIEWPORT");
+        features.close_window = has("_NET_CLOSE_WINDOW");
+        features.moveresize_window = has("_NET_MOVERESIZE_WINDOW");
+
+        for name in names {
+            if name.starts_with("_NET_WM_STATE_") {
+                features.window_states.push(name);
+            } else if name.starts_with("_NET_WM_ACTION_") {
+                features.actions.push(name);
+            } else if PROTOCOLS.contains(&name.as_str()) {
+                features.protocols.push(name);
+            } else if ROOT_PROPERTIES.contains(&name.as_str()) {
+                features.root_properties.push(name);
+            } else {
+                features.other.push(name);
+            }
+        }
+        features
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supported_features() {
+        let names = [
+            "_NET_WM_STATE_FULLSCREEN",
+            "_NET_WM_STATE_MAXIMIZED_HORZ",
+            "_NET_WM_ACTION_MOVE",
+            "_NET_WM_PING",
+            "_NET_DESKTOP_NAMES",
+            "_NET_WM_NAME",
+        ];
+        let supported = names.iter().enumerate().map(|(i, x)| (i as u32, x.to_string())).collect();
+        let features = SupportedFeatures::from(&supported);
+
+        assert_eq!(features.window_states, vec!["_NET_WM_STATE_FULLSCREEN", "_NET_WM_STATE_MAXIMIZED_HORZ"]);
+        assert_eq!(features.actions, vec!["_NET_WM_ACTION_MOVE"]);
+        assert_eq!(features.protocols, vec!["_NET_WM_PING"]);
+        assert_eq!(features.root_properties, vec!["_NET_DESKTOP_NAMES"]);
+        assert_eq!(features.other, vec!["_NET_WM_NAME"]);
+        assert!(features.fullscreen);
+        assert!(features.desktop_names);
+        assert!(!features.sticky);
+        assert!(!features.maximize);
+    }
+}
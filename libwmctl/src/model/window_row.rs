@@ -0,0 +1,14 @@
+use crate::Geometry;
+
+/// WindowRow provides the details of a single window as shown by a listing e.g. `wmctl list`
+/// gathered in one batched snapshot rather than querying each field separately
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WindowRow {
+    pub id: u32,            // id of the window
+    pub desktop: i32,       // desktop numbered from 1 or -1 for sticky windows
+    pub pid: i32,           // process id of the application or 0 if not set
+    pub host: String,       // machine the application is running on
+    pub class: String,      // window class which is typically the application's name
+    pub title: String,      // window title
+    pub geometry: Geometry, // visual geometry of the window including decorations
+}
@@ -0,0 +1,115 @@
+use crate::model::*;
+
+/// WmHints provides the application's ICCCM WM_HINTS e.g. whether the window accepts input focus
+/// and the state it should start in. The raw `flags` indicate which of the values are present as
+/// values not present are stored as zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WmHints {
+    pub flags: WmHintsFlags, // bit mask of the values present
+    pub input: bool,         // window relies on the window manager to get input focus
+    pub initial_state: u32,  // state to map the window in, 1 normal or 3 iconic
+    pub icon_pixmap: u32,    // pixmap to use as the icon
+    pub icon_window: u32,    // window to use as the icon
+    pub icon_x: i32,         // initial x position of the icon
+    pub icon_y: i32,         // initial y position of the icon
+    pub icon_mask: u32,      // pixmap to use as the icon's mask
+    pub window_group: u32,   // group leader of the window
+}
+
+impl WmHints {
+    /// Create the hints from the WM_HINTS values. Missing values are treated as zero.
+    ///
+    /// ### Arguments
+    /// * `values` - flags, input, initial_state, icon_pixmap, icon_window, icon_x, icon_y,
+    ///   icon_mask, window_group
+    pub fn from(values: &[u32]) -> WmHints {
+        let v = |i: usize| values.get(i).copied().unwrap_or_default();
+        WmHints {
+            flags: v(0),
+            input: v(1) != 0,
+            initial_state: v(2),
+            icon_pixmap: v(3),
+            icon_window: v(4),
+            icon_x: v(5) as i32,
+            icon_y: v(6) as i32,
+            icon_mask: v(7),
+            window_group: v(8),
+        }
+    }
+
+    /// Convert the hints into the 9 WM_HINTS values
+    pub fn values(&self) -> [u32; 9] {
+        [
+            self.flags,
+            self.input as u32,
+            self.initial_state,
+            self.icon_pixmap,
+            self.icon_window,
+            self.icon_x as u32,
+            self.icon_y as u32,
+            self.icon_mask,
+            self.window_group,
+        ]
+    }
+
+    /// Set whether the window accepts input focus marking the value as present
+    pub fn set_input(&mut self, input: bool) {
+        self.input = input;
+        self.flags |= WM_HINTS_INPUT;
+    }
+
+    /// Set the state the window should be mapped in marking the value as present
+    pub fn set_initial_state(&mut self, state: u32) {
+        self.initial_state = state;
+        self.flags |= WM_HINTS_STATE;
+    }
+
+    /// Set or clear the urgency hint
+    pub fn set_urgency(&mut self, urgent: bool) {
+        if urgent {
+            self.flags |= WM_HINTS_URGENCY;
+        } else {
+            self.flags &= !WM_HINTS_URGENCY;
+        }
+    }
+
+    /// Check if the application declared whether it accepts input focus
+    pub fn has_input(&self) -> bool {
+        self.flags & WM_HINTS_INPUT != 0
+    }
+
+    /// Check if the application declared an initial state
+    pub fn has_initial_state(&self) -> bool {
+        self.flags & WM_HINTS_STATE != 0
+    }
+
+    /// Check if the urgency hint is set
+    pub fn is_urgent(&self) -> bool {
+        self.flags & WM_HINTS_URGENCY != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wm_hints() {
+        let hints = WmHints::from(&[WM_HINTS_INPUT | WM_HINTS_URGENCY, 1, 0, 0, 0, -5i32 as u32, 10]);
+        assert!(hints.input && hints.has_input() && hints.is_urgent());
+        assert!(!hints.has_initial_state());
+        assert_eq!((hints.icon_x, hints.icon_y, hints.window_group), (-5, 10, 0));
+        assert_eq!(WmHints::from(&hints.values()), hints);
+    }
+
+    #[test]
+    fn test_wm_hints_setters() {
+        let mut hints = WmHints::default();
+        hints.set_input(true);
+        hints.set_initial_state(3);
+        hints.set_urgency(true);
+        assert_eq!(hints.values(), [WM_HINTS_INPUT | WM_HINTS_STATE | WM_HINTS_URGENCY, 1, 3, 0, 0, 0, 0, 0, 0]);
+        hints.set_urgency(false);
+        assert!(!hints.is_urgent());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::DESKTOP_STICKY;
+
+/// WorkspaceSummary provides everything a status bar typically shows about the desktops in a
+/// single structure such that it can be gathered with a minimum of round trips each tick.
+/// * Serializable with the `serde` feature enabled
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceSummary {
+    pub desktops: u32,         // number of desktops
+    pub names: Vec<String>,    // name of each desktop, `Desktop N` if not named
+    pub active: u32,           // active desktop numbered from 1
+    pub windows: Vec<usize>,   // number of windows on each desktop not counting sticky windows
+    pub showing_desktop: bool, // windows are hidden to show the desktop
+}
+
+impl WorkspaceSummary {
+    /// Count the windows on each desktop from the raw zero based _NET_WM_DESKTOP values of the
+    /// windows. Sticky windows and windows on desktops out of range aren't counted.
+    ///
+    /// ### Arguments
+    /// * `desktops` - number of desktops
+    /// * `values` - raw _NET_WM_DESKTOP value for each window
+    pub(crate) fn count_windows(desktops: u32, values: &[u32]) -> Vec<usize> {
+        let mut counts = vec![0; desktops as usize];
+        for &x in values.iter().filter(|&&x| x != DESKTOP_STICKY) {
+            if let Some(count) = counts.get_mut(x as usize) {
+                *count += 1;
+            }
+        }
+        counts
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for WorkspaceSummary {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("WorkspaceSummary", 5)?;
+        state.serialize_field("desktops", &self.desktops)?;
+        state.serialize_field("names", &self.names)?;
+        state.serialize_field("active", &self.active)?;
+        state.serialize_field("windows", &self.windows)?;
+        state.serialize_field("showing_desktop", &self.showing_desktop)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_windows() {
+        let values = [0, 1, 1, DESKTOP_STICKY, 3, 7];
+        assert_eq!(WorkspaceSummary::count_windows(4, &values), vec![1, 2, 0, 1]);
+        assert_eq!(WorkspaceSummary::count_windows(0, &values), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,256 @@
+// ## References
+// * https://www.w3.org/TR/png/
+// * https://www.x.org/releases/X11R7.7/doc/xproto/x11protocol.html#requests:GetImage
+//
+// ## Details
+// * X images are returned in the server's pixel format which is described by the bits per pixel
+//   and scanline padding of the depth's pixmap format, the server's image byte order and the
+//   channel masks of the window's visual. Masks rather than byte positions are used to pick out the
+//   channels such that both BGR and RGB servers are handled.
+// * Thumbnails are only ever scaled down using a box filter averaging all source pixels that fall
+//   within each destination pixel.
+// * PNGs are encoded as 8 bit RGBA without any filtering which keeps the encoder trivial.
+use crate::{WmCtlError, WmCtlResult};
+
+/// PixelFormat describes how the pixels of an X image are laid out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct PixelFormat {
+    pub(crate) depth: u8,          // number of significant bits per pixel
+    pub(crate) bits_per_pixel: u8, // number of bits each pixel occupies
+    pub(crate) scanline_pad: u8,   // number of bits each row is padded to
+    pub(crate) msb_first: bool,    // pixels are stored most significant byte first
+    pub(crate) red_mask: u32,      // bits of the pixel holding red
+    pub(crate) green_mask: u32,    // bits of the pixel holding green
+    pub(crate) blue_mask: u32,     // bits of the pixel holding blue
+}
+
+/// Convert the raw X image data into 8 bit RGBA. Only depth 32 images carry an alpha channel in
+/// the bits not covered by the color masks, all others are treated as opaque.
+///
+/// ### Arguments
+/// * `data` - raw image data as returned by GetImage
+/// * `w` - width of the image
+/// * `h` - height of the image
+/// * `format` - layout of the pixels in the image data
+pub(crate) fn to_rgba(data: &[u8], w: u32, h: u32, format: &PixelFormat) -> WmCtlResult<Vec<u8>> {
+    let bytes = match format.bits_per_pixel {
+        16 | 24 | 32 => format.bits_per_pixel as usize / 8,
+        _ => return Err(WmCtlError::Unsupported(format!("{} bits per pixel", format.bits_per_pixel)).into()),
+    };
+    let pad = format.scanline_pad.max(8) as usize;
+    let stride = (w as usize * format.bits_per_pixel as usize).div_ceil(pad) * pad / 8;
+    if data.len() < stride * h as usize {
+        return Err(WmCtlError::Unsupported(format!("image data of {} bytes", data.len())).into());
+    }
+    let alpha_mask = match format.depth {
+        32 => !(format.red_mask | format.green_mask | format.blue_mask),
+        _ => 0,
+    };
+
+    let mut rgba = Vec::with_capacity(w as usize * h as usize * 4);
+    for row in data.chunks(stride).take(h as usize) {
+        for px in row[..w as usize * bytes].chunks_exact(bytes) {
+            let pixel = match format.msb_first {
+                true => px.iter().fold(0u32, |acc, x| (acc << 8) | *x as u32),
+                false => px.iter().rev().fold(0u32, |acc, x| (acc << 8) | *x as u32),
+            };
+            rgba.push(channel(pixel, format.red_mask));
+            rgba.push(channel(pixel, format.green_mask));
+            rgba.push(channel(pixel, format.blue_mask));
+            rgba.push(if alpha_mask == 0 { 0xFF } else { channel(pixel, alpha_mask) });
+        }
+    }
+    Ok(rgba)
+}
+
+/// Extract the channel covered by the given mask scaled to 8 bits
+fn channel(pixel: u32, mask: u32) -> u8 {
+    if mask == 0 {
+        return 0;
+    }
+    let value = (pixel & mask) >> mask.trailing_zeros();
+    let max = mask >> mask.trailing_zeros();
+    (value as u64 * 0xFF / max as u64) as u8
+}
+
+/// Compute the size of the thumbnail fitting within the given maximum dimension while keeping the
+/// aspect ratio. Images already small enough are left as is.
+///
+/// ### Arguments
+/// * `w` - width of the image
+/// * `h` - height of the image
+/// * `max_dim` - maximum width and height of the thumbnail
+pub(crate) fn thumbnail_size(w: u32, h: u32, max_dim: u32) -> (u32, u32) {
+    let max_dim = max_dim.max(1);
+    if w <= max_dim && h <= max_dim {
+        return (w, h);
+    }
+    if w >= h {
+        (max_dim, ((h as u64 * max_dim as u64 / w as u64) as u32).max(1))
+    } else {
+        (((w as u64 * max_dim as u64 / h as u64) as u32).max(1), max_dim)
+    }
+}
+
+/// Scale the RGBA image down to the given size by averaging the source pixels covered by each
+/// destination pixel
+///
+/// ### Arguments
+/// * `rgba` - 8 bit RGBA image data
+/// * `w` - width of the image
+/// * `h` - height of the image
+/// * `tw` - width to scale to
+/// * `th` - height to scale to
+pub(crate) fn scale_rgba(rgba: &[u8], w: u32, h: u32, tw: u32, th: u32) -> Vec<u8> {
+    if (w, h) == (tw, th) {
+        return rgba.to_vec();
+    }
+    let (w, h, tw, th) = (w as usize, h as usize, tw as usize, th as usize);
+    let mut scaled = Vec::with_capacity(tw * th * 4);
+    for ty in 0..th {
+        let (y0, y1) = (ty * h / th, ((ty + 1) * h / th).max(ty * h / th + 1));
+        for tx in 0..tw {
+            let (x0, x1) = (tx * w / tw, ((tx + 1) * w / tw).max(tx * w / tw + 1));
+            let mut sum = [0usize; 4];
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    let i = (y * w + x) * 4;
+                    for (c, total) in sum.iter_mut().enumerate() {
+                        *total += rgba[i + c] as usize;
+                    }
+                }
+            }
+            let count = (y1 - y0) * (x1 - x0);
+            scaled.extend(sum.iter().map(|x| (x / count) as u8));
+        }
+    }
+    scaled
+}
+
+/// Encode the RGBA image as a PNG
+///
+/// ### Arguments
+/// * `rgba` - 8 bit RGBA image data
+/// * `w` - width of the image
+/// * `h` - height of the image
+pub(crate) fn encode(rgba: &[u8], w: u32, h: u32) -> Vec<u8> {
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+    // Defined as: width, height, bit depth, color type 6 (RGBA), compression, filter, interlace
+    let mut header = Vec::with_capacity(13);
+    header.extend(w.to_be_bytes());
+    header.extend(h.to_be_bytes());
+    header.extend([8, 6, 0, 0, 0]);
+    chunk(&mut png, b"IHDR", &header);
+
+    // Each scanline is prefixed with its filter type which is always none
+    let mut raw = Vec::with_capacity((w as usize * 4 + 1) * h as usize);
+    for row in rgba.chunks_exact((w as usize * 4).max(1)).take(h as usize) {
+        raw.push(0);
+        raw.extend(row);
+    }
+    chunk(&mut png, b"IDAT", &miniz_oxide::deflate::compress_to_vec_zlib(&raw, 6));
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// Append the chunk of the given kind to the PNG
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+/// Compute the CRC-32 used by PNG chunks
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, x| {
+        (0..8).fold(crc ^ *x as u32, |crc, _| if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_rgba() {
+        // 2x1 BGRX image as stored by a little endian depth 24 server with padding on the row
+        let mut format = PixelFormat {
+            depth: 24,
+            bits_per_pixel: 32,
+            scanline_pad: 32,
+            msb_first: false,
+            red_mask: 0xFF0000,
+            green_mask: 0x00FF00,
+            blue_mask: 0x0000FF,
+        };
+        let data = [0x30, 0x20, 0x10, 0x00, 0x03, 0x02, 0x01, 0x00];
+        assert_eq!(to_rgba(&data, 2, 1, &format).unwrap(), [0x10, 0x20, 0x30, 0xFF, 0x01, 0x02, 0x03, 0xFF]);
+
+        // Big endian servers store the same pixel in the opposite byte order
+        format.msb_first = true;
+        let data = [0x00, 0x10, 0x20, 0x30];
+        assert_eq!(to_rgba(&data, 1, 1, &format).unwrap(), [0x10, 0x20, 0x30, 0xFF]);
+
+        // RGB visuals swap the red and blue masks and depth 32 carries alpha
+        format = PixelFormat {
+            depth: 32,
+            red_mask: 0x0000FF,
+            blue_mask: 0xFF0000,
+            msb_first: false,
+            ..format
+        };
+        let data = [0x10, 0x20, 0x30, 0x80];
+        assert_eq!(to_rgba(&data, 1, 1, &format).unwrap(), [0x10, 0x20, 0x30, 0x80]);
+
+        // 16 bit RGB565 scales channels up to 8 bits
+        format = PixelFormat {
+            depth: 16,
+            bits_per_pixel: 16,
+            red_mask: 0xF800,
+            green_mask: 0x07E0,
+            blue_mask: 0x001F,
+            ..format
+        };
+        assert_eq!(to_rgba(&[0x1F, 0xF8, 0, 0], 1, 1, &format).unwrap(), [0xFF, 0, 0xFF, 0xFF]);
+
+        // Short data and unsupported formats are rejected
+        assert!(to_rgba(&[0, 0], 2, 1, &format).is_err());
+        format.bits_per_pixel = 1;
+        assert!(to_rgba(&[0; 4], 1, 1, &format).is_err());
+    }
+
+    #[test]
+    fn test_thumbnail_size() {
+        assert_eq!(thumbnail_size(1920, 1080, 256), (256, 144));
+        assert_eq!(thumbnail_size(1080, 1920, 256), (144, 256));
+        assert_eq!(thumbnail_size(100, 50, 256), (100, 50));
+        assert_eq!(thumbnail_size(4000, 1, 100), (100, 1));
+        assert_eq!(thumbnail_size(10, 10, 0), (1, 1));
+    }
+
+    #[test]
+    fn test_scale_rgba() {
+        // 2x2 down to 1x1 averages all four pixels
+        let rgba = [0, 0, 0, 255, 100, 100, 100, 255, 200, 200, 200, 255, 100, 100, 100, 255];
+        assert_eq!(scale_rgba(&rgba, 2, 2, 1, 1), [100, 100, 100, 255]);
+
+        // 3x1 down to 2x1 covers at least one source pixel per destination pixel
+        let rgba = [10, 0, 0, 0, 20, 0, 0, 0, 30, 0, 0, 0];
+        assert_eq!(scale_rgba(&rgba, 3, 1, 2, 1), [10, 0, 0, 0, 25, 0, 0, 0]);
+        assert_eq!(scale_rgba(&rgba, 3, 1, 3, 1), rgba);
+    }
+
+    #[test]
+    fn test_encode() {
+        let png = encode(&[0xFF, 0, 0, 0xFF], 1, 1);
+        assert_eq!(&png[..8], &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]);
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..29], &[0, 0, 0, 1, 0, 0, 0, 1, 8, 6, 0, 0, 0]);
+        assert_eq!(&png[png.len() - 12..], &[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+}
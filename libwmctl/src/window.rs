@@ -1,6 +1,13 @@
+use std::time::{Duration, Instant};
 use tracing::debug;
 
-use crate::{model::*, WmCtlResult, WM};
+#[cfg(feature = "glob")]
+use crate::glob::glob_match;
+use crate::{
+    model::*,
+    winmgr::{WinMgr, WindowChange},
+    ErrorWrapper, WmCtlError, WmCtlResult, WM,
+};
 
 /// Window provides a higer level interfacefor manipulating windows.
 #[derive(Clone)]
@@ -39,6 +46,81 @@ impl Window {
         WM().read().unwrap().window_pid(self.id)
     }
 
+    /// Get the hostname of the machine the window's client is running on as set in WM_CLIENT_MACHINE
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let host = win.client_machine().unwrap();
+    /// ```
+    pub fn client_machine(&self) -> WmCtlResult<String> {
+        WM().read().unwrap().window_client_machine(self.id)
+    }
+
+    /// Get the window's pid only when its client is running on this machine as pids of clients on
+    /// other machines connected to the same display are meaningless locally. Clients that don't
+    /// set WM_CLIENT_MACHINE are assumed to be local.
+    ///
+    /// ### Returns
+    /// * the pid or `None` if the client is running on another machine
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// if let Some(pid) = win.local_pid().unwrap() {
+    ///     println!("{}", pid);
+    /// }
+    /// ```
+    pub fn local_pid(&self) -> WmCtlResult<Option<i32>> {
+        let pid = self.pid()?;
+        let host = match self.client_machine() {
+            Ok(host) => host,
+            Err(ErrorWrapper::WmCtl(WmCtlError::PropertyNotFound(_))) => return Ok(Some(pid)),
+            Err(e) => return Err(e),
+        };
+        let local = local_hostname().is_none_or(|x| same_host(&x, &host));
+        debug!("local_pid: id: {}, pid: {}, host: {}, local: {}", self.id, pid, host, local);
+        Ok(local.then_some(pid))
+    }
+
+    /// Get the time of the last user activity in the window
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let time = win.user_time().unwrap();
+    /// ```
+    pub fn user_time(&self) -> WmCtlResult<u32> {
+        WM().read().unwrap().window_user_time(self.id)
+    }
+
+    /// Get the window's ICCCM size hints
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let hints = win.normal_hints().unwrap();
+    /// ```
+    pub fn normal_hints(&self) -> WmCtlResult<SizeHints> {
+        WM().read().unwrap().window_normal_hints(self.id)
+    }
+
+    /// Get the raw flags indicating which of the window's ICCCM size hints were declared
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let declared_min = win.normal_hints_flags().unwrap() & SIZE_HINTS_P_MIN_SIZE != 0;
+    /// ```
+    pub fn normal_hints_flags(&self) -> WmCtlResult<SizeHintsFlags> {
+        Ok(self.normal_hints()?.flags)
+    }
+
     /// Get window name
     ///
     /// ### Examples
@@ -48,7 +130,69 @@ impl Window {
     /// let name = win.name().unwrap();
     /// ```
     pub fn name(&self) -> WmCtlResult<String> {
-        WM().read().unwrap().window_name(self.id)
+        WM().read().unwrap().window_name(self.id, false)
+    }
+
+    /// Get window name falling back on the name of its immediate children when the window's own
+    /// name is empty. This recovers titles for apps e.g. certain Java/SWT ones that only name a
+    /// child window at the cost of an extra round trip when the fallback is needed.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let name = win.name_deep().unwrap();
+    /// ```
+    pub fn name_deep(&self) -> WmCtlResult<String> {
+        WM().read().unwrap().window_name(self.id, true)
+    }
+
+    /// Check if the window name matches the given glob pattern where `*` matches any number of
+    /// characters, `?` matches a single character and `[a-z]` matches a single character in the set.
+    /// Requires the `glob` feature.
+    ///
+    /// ### Arguments
+    /// * `pattern` - glob pattern to match the window name against
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let firefox = win.title_matches("*- Mozilla Firefox").unwrap();
+    /// ```
+    #[cfg(feature = "glob")]
+    pub fn title_matches(&self, pattern: &str) -> WmCtlResult<bool> {
+        Ok(glob_match(pattern, &self.name()?))
+    }
+
+    /// Check if the window name matches the given regular expression anywhere in the name unless
+    /// anchored with `^` and `$`. Requires the `regex` feature.
+    ///
+    /// ### Arguments
+    /// * `pattern` - regular expression to match the window name against
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let firefox = win.title_matches_regex(r"- Mozilla Firefox$").unwrap();
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn title_matches_regex(&self, pattern: &str) -> WmCtlResult<bool> {
+        let re = regex::Regex::new(pattern).map_err(|x| WmCtlError::InvalidPattern(x.to_string()))?;
+        Ok(re.is_match(&self.name()?))
+    }
+
+    /// Check if the window has a non empty name
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.has_name()
+    /// ```
+    pub fn has_name(&self) -> bool {
+        self.name().is_ok_and(|x| !x.is_empty())
     }
 
     /// Get window class which is typically the the application's name
@@ -78,6 +222,77 @@ impl Window {
         WM().read().unwrap().window_kind(self.id)
     }
 
+    /// Check if the window's class case insensitively equals any of the given classes. Errors
+    /// reading the class are treated as no match to keep predicates terse.
+    ///
+    /// ### Arguments
+    /// * `classes` - classes to match against
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let browser = win.class_matches_any(&["firefox", "chromium", "brave"]);
+    /// ```
+    pub fn class_matches_any(&self, classes: &[&str]) -> bool {
+        self.class().is_ok_and(|class| classes.iter().any(|x| x.eq_ignore_ascii_case(&class)))
+    }
+
+    /// Check if the window is addressed by the given selector. Class matches are case insensitive
+    /// while name and role matches are exact. Errors reading the property are treated as no match.
+    ///
+    /// ### Arguments
+    /// * `sel` - selector to match against
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let firefox = win.matches(&Selector::ByClass("firefox".to_owned()));
+    /// ```
+    pub fn matches(&self, sel: &Selector) -> bool {
+        match sel {
+            Selector::ById(id) => self.id == *id,
+            Selector::ByClass(class) => self.class_matches_any(&[class]),
+            Selector::ByName(name) => self.name().is_ok_and(|x| x == *name),
+            Selector::ByPid(pid) => self.pid().is_ok_and(|x| x == *pid),
+            Selector::ByRole(role) => self.role().is_ok_and(|x| x == *role),
+            Selector::Active => WM().read().unwrap().active_window().is_ok_and(|x| x == self.id),
+        }
+    }
+
+    /// Get window role which applications set to tell apart their windows e.g. a browser window
+    /// from its preferences dialog. PropertyNotFound is returned if the window doesn't set a role.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let role = win.role().unwrap();
+    /// ```
+    pub fn role(&self) -> WmCtlResult<String> {
+        WM().read().unwrap().window_role(self.id)
+    }
+
+    /// Check if the window is on the given monitor. A window straddling two monitors is considered
+    /// to be on the monitor its center lies on.
+    ///
+    /// ### Arguments
+    /// * `index` - index of the monitor in the screen layout
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let on_first = win.is_on_monitor(0).unwrap();
+    /// ```
+    pub fn is_on_monitor(&self, index: usize) -> WmCtlResult<bool> {
+        let layout = WM().read().unwrap().screen_layout()?;
+        let monitor = layout.monitors.get(index).ok_or(WmCtlError::InvalidMonitor(index))?;
+        let (x, y, w, h) = self.visual_geometry()?;
+        Ok(monitor.contains(x + w as i32 / 2, y + h as i32 / 2))
+    }
+
     /// Get window state
     ///
     /// ### Examples
@@ -90,6 +305,116 @@ impl Window {
         WM().read().unwrap().window_state(self.id)
     }
 
+    /// Get the ICCCM state the window manager set on the window along with its icon window. This
+    /// is the authoritative minimized or normal signal on window managers that don't set the EWMH
+    /// hidden state.
+    ///
+    /// ### Returns
+    /// * `(state, icon)` where icon is the window used as the icon if any
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let (state, icon) = win.icccm_state().unwrap();
+    /// ```
+    pub fn icccm_state(&self) -> WmCtlResult<(IcccmState, Option<u32>)> {
+        WM().read().unwrap().window_wm_state(self.id)
+    }
+
+    /// Check if the window is minimized. The EWMH hidden state is checked first falling back on
+    /// the ICCCM iconic state for older window managers that don't set it.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let minimized = win.is_minimized().unwrap();
+    /// ```
+    pub fn is_minimized(&self) -> WmCtlResult<bool> {
+        let wm = WM().read().unwrap();
+        // Older window managers may not set _NET_WM_STATE at all
+        if wm.window_state(self.id).is_ok_and(|x| x.contains(&State::Hidden)) {
+            return Ok(true);
+        }
+        Ok(wm.window_wm_state(self.id)?.0 == IcccmState::Iconic)
+    }
+
+    /// Minimize the window then wait until the window manager has done so
+    ///
+    /// ### Returns
+    /// * `true` if the window was minimized in time or `false` if the window manager didn't
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.minimize().unwrap();
+    /// ```
+    pub fn minimize(&self) -> WmCtlResult<bool> {
+        WM().read().unwrap().minimize_window(self.id)?;
+        self.wait_minimized(true)
+    }
+
+    /// Restore the minimized window by activating it then wait until the window manager has done so
+    ///
+    /// ### Returns
+    /// * `true` if the window was restored in time or `false` if the window manager didn't
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.unminimize().unwrap();
+    /// ```
+    pub fn unminimize(&self) -> WmCtlResult<bool> {
+        WM().read().unwrap().unminimize_window(self.id)?;
+        self.wait_minimized(false)
+    }
+
+    /// Poll until the window's minimized state matches the given state or a second has passed
+    fn wait_minimized(&self, minimized: bool) -> WmCtlResult<bool> {
+        let deadline = Instant::now() + Duration::from_secs(1);
+        loop {
+            if self.is_minimized()? == minimized {
+                return Ok(true);
+            }
+            if Instant::now() >= deadline {
+                debug!("wait_minimized: id: {}, minimized: {}, applied: false", self.id, minimized);
+                return Ok(false);
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    /// Get the actions the window manager allows for the window
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let actions = win.allowed_actions().unwrap();
+    /// ```
+    pub fn allowed_actions(&self) -> WmCtlResult<Vec<WinAction>> {
+        WM().read().unwrap().window_allowed_actions(self.id)
+    }
+
+    /// Set the actions the window manager should allow for a window created by the caller e.g.
+    /// to forbid closing or minimizing a kiosk window
+    ///
+    /// ### Arguments
+    /// * `actions` - actions to allow
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.set_allowed_actions(&[WinAction::Move, WinAction::Resize]).unwrap();
+    /// ```
+    pub fn set_allowed_actions(&self, actions: &[WinAction]) -> WmCtlResult<()> {
+        WM().read().unwrap().set_allowed_actions(self.id, actions)
+    }
+
     /// Get window parent
     ///
     /// ### Examples
@@ -102,6 +427,64 @@ impl Window {
         WM().read().unwrap().window_parent(self.id)
     }
 
+    /// Get the chain of parents of the window from its immediate parent up to but not including
+    /// the root window. Useful to diagnose how deeply the window manager has reparented a client.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// for parent in win.ancestors().unwrap() {
+    ///     println!("parent: {}", parent.id);
+    /// }
+    /// ```
+    pub fn ancestors(&self) -> WmCtlResult<Vec<Window>> {
+        Ok(WM().read().unwrap().window_ancestors(self.id)?.into_iter().map(Window::new).collect())
+    }
+
+    /// Get the window's ICCCM WM_HINTS e.g. whether it accepts input focus
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let hints = win.hints().unwrap();
+    /// ```
+    pub fn hints(&self) -> WmCtlResult<WmHints> {
+        WM().read().unwrap().window_wm_hints(self.id)
+    }
+
+    /// Set the window's ICCCM WM_HINTS e.g. to make a self created overlay window focusable
+    ///
+    /// ### Arguments
+    /// * `hints` - hints to set
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let mut hints = win.hints().unwrap();
+    /// hints.set_input(true);
+    /// win.set_hints(hints).unwrap();
+    /// ```
+    pub fn set_hints(&self, hints: WmHints) -> WmCtlResult<()> {
+        WM().read().unwrap().set_wm_hints(self.id, hints)
+    }
+
+    /// Get the events selected on the window by all clients and by this client e.g. to diagnose
+    /// why events sent to the window aren't being processed
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let masks = win.event_mask().unwrap();
+    /// println!("{:?}", EventMasks::names(masks.all));
+    /// ```
+    pub fn event_mask(&self) -> WmCtlResult<EventMasks> {
+        WM().read().unwrap().window_event_mask(self.id)
+    }
+
     /// Get window desktop
     ///
     /// ### Examples
@@ -114,7 +497,22 @@ impl Window {
         WM().read().unwrap().window_desktop(self.id)
     }
 
-    /// Get window geometry
+    /// Get the desktop the window is effectively on taking into account viewport based window
+    /// managers e.g. compiz where the desktop is determined by the window's position.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let desktop = win.effective_desktop().unwrap();
+    /// ```
+    pub fn effective_desktop(&self) -> WmCtlResult<i32> {
+        WM().read().unwrap().window_effective_desktop(self.id)
+    }
+
+    /// Get window geometry. The geometry is never cached and always reflects the server's current
+    /// view which on window managers that animate moves may still be mid animation, use
+    /// `geometry_after_idle()` to get the settled geometry.
     ///
     /// ### Examples
     /// ```ignore
@@ -126,6 +524,49 @@ impl Window {
         WM().read().unwrap().window_geometry(self.id)
     }
 
+    /// Get window geometry once the window manager has settled i.e. no configure notifications have
+    /// been seen on the window for a short quiet period. This gives the true geometry after a move
+    /// or resize on window managers that animate them. The geometry is read regardless once the
+    /// timeout elapses.
+    ///
+    /// ### Arguments
+    /// * `timeout` - maximum time to wait for the window manager to settle
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.move_resize_reporting(Some(0), Some(0), Some(800), Some(600)).unwrap();
+    /// let (x, y, w, h) = win.geometry_after_idle(Duration::from_secs(1)).unwrap();
+    /// ```
+    pub fn geometry_after_idle(&self, timeout: Duration) -> WmCtlResult<(i32, i32, u32, u32)> {
+        WM().read().unwrap().select_window_events(self.id)?;
+
+        // Lock is released between checks so other callers aren't blocked while waiting
+        let quiet = Duration::from_millis(100);
+        let start = Instant::now();
+        let mut last = start;
+        loop {
+            let change = WM().read().unwrap().poll_window_configured(self.id)?;
+            match change {
+                WindowChange::Changed(()) => last = Instant::now(),
+                WindowChange::Destroyed => break,
+                WindowChange::Pending => {},
+            }
+            let now = Instant::now();
+            if now.duration_since(last) >= quiet {
+                debug!("geometry_after_idle: id: {}, settled: {:?}", self.id, now.duration_since(start));
+                break;
+            }
+            if now.duration_since(start) >= timeout {
+                debug!("geometry_after_idle: id: {}, timed out", self.id);
+                break;
+            }
+            std::thread::sleep(WATCH_INTERVAL);
+        }
+        WM().read().unwrap().window_geometry(self.id)
+    }
+
     /// Get visual window geometry
     ///
     /// ### Examples
@@ -138,6 +579,22 @@ impl Window {
         WM().read().unwrap().window_visual_geometry(self.id)
     }
 
+    /// Check if the visible area of the window intersects the given rectangle e.g. to avoid
+    /// placing windows on top of pinned ones. Touching edges don't count as intersecting.
+    ///
+    /// ### Arguments
+    /// * `other` - rectangle to check against
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let covered = win.intersects(&Geometry::new(0, 0, 800, 600)).unwrap();
+    /// ```
+    pub fn intersects(&self, other: &Geometry) -> WmCtlResult<bool> {
+        Ok(Geometry::from(self.visual_geometry()?).intersection(other).is_some())
+    }
+
     /// Get window frame border values added by the window manager
     ///
     /// ### Examples
@@ -147,7 +604,7 @@ impl Window {
     /// let (l, r, t, b) = win.borders();
     /// ```
     pub fn borders(&self) -> Border {
-        WM().read().unwrap().window_borders(self.id).unwrap_or(Border::default())
+        WM().read().unwrap().window_borders(self.id).unwrap_or_default()
     }
 
     /// Determine if this window is a GTK application
@@ -162,105 +619,1168 @@ impl Window {
         WM().read().unwrap().window_is_gtk(self.id)
     }
 
-    /// Get window GNOME border values added by GTK
+    /// Get the application's name for display e.g. in a taskbar. The GTK application id is
+    /// preferred as it is the most stable followed by the WM_CLASS instance then the class.
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let win = window(12345);
-    /// let (l, r, t, b) = win.gtk_borders();
+    /// let app_name = win.app_name().unwrap();
+    /// ```
+    pub fn app_name(&self) -> WmCtlResult<String> {
+        let wm = WM().read().unwrap();
+        if let Some(app_id) = wm.window_gtk_app_id(self.id)?.filter(|x| !x.is_empty()) {
+            return Ok(app_id);
+        }
+        let instance = wm.window_instance(self.id)?;
+        if !instance.is_empty() {
+            return Ok(instance);
+        }
+        wm.window_class(self.id)
+    }
+
+    /// Get the GTK application id e.g. `org.gnome.Nautilus` or None for non GTK applications
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let app_id = win.app_id().unwrap();
+    /// ```
+    pub fn app_id(&self) -> WmCtlResult<Option<String>> {
+        WM().read().unwrap().window_gtk_app_id(self.id)
+    }
+
+    /// Get the window's opacity from 0.0 fully transparent to 1.0 fully opaque
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let opacity = win.opacity().unwrap();
+    /// ```
+    pub fn opacity(&self) -> WmCtlResult<f64> {
+        WM().read().unwrap().window_opacity(self.id)
+    }
+
+    /// Set the window's opacity from 0.0 fully transparent to 1.0 fully opaque. Values outside the
+    /// range are clamped and CompositorNotFound is returned when no compositing manager is running
+    /// as the opacity would have no visible effect.
+    ///
+    /// ### Arguments
+    /// * `opacity` - opacity to set
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.set_opacity(0.8).unwrap();
+    /// ```
+    pub fn set_opacity(&self, opacity: f64) -> WmCtlResult<()> {
+        WM().read().unwrap().set_window_opacity(self.id, opacity)
+    }
+
+    /// Remove the window's opacity returning it to the opacity decided by the compositing manager
+    /// e.g. to fully restore a window that was temporarily dimmed
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.clear_opacity().unwrap();
+    /// ```
+    pub fn clear_opacity(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().clear_window_opacity(self.id)
+    }
+
+    /// Toggle the window's opacity between the two given values e.g. to dim and undim the window.
+    /// The window is set to `b` if its opacity is currently near `a` otherwise it is set to `a`.
+    /// Windows without an opacity set are treated as fully opaque.
+    ///
+    /// ### Arguments
+    /// * `a` - first opacity from 0.0 fully transparent to 1.0 fully opaque
+    /// * `b` - second opacity from 0.0 fully transparent to 1.0 fully opaque
+    ///
+    /// ### Returns
+    /// * the window's new opacity
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let opacity = win.toggle_opacity(1.0, 0.6).unwrap();
+    /// ```
+    pub fn toggle_opacity(&self, a: f64, b: f64) -> WmCtlResult<f64> {
+        let wm = WM().read().unwrap();
+        let opacity = translate_opacity_toggle(wm.window_opacity(self.id)?, a, b);
+        wm.set_window_opacity(self.id, opacity)?;
+        Ok(opacity)
+    }
+
+    /// Watch the window's opacity yielding the new opacity each time it changes. Bursts of changes
+    /// are coalesced into a single value and the iterator ends when the window is destroyed.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// for opacity in win.watch_opacity() {
+    ///     println!("opacity: {}", opacity.unwrap());
+    /// }
+    /// ```
+    pub fn watch_opacity(&self) -> impl Iterator<Item = WmCtlResult<f64>> {
+        let id = self.id;
+        let mut selected = false;
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            if !selected {
+                selected = true;
+                if let Err(e) = WM().read().unwrap().select_window_events(id) {
+                    done = true;
+                    return Some(Err(e));
+                }
+            }
+            match wait_change(|wm| wm.poll_window_opacity(id)) {
+                Ok(Some(opacity)) => Some(Ok(opacity)),
+                Ok(None) => {
+                    done = true;
+                    None
+                },
+                Err(e) => {
+                    done = true;
+                    Some(Err(e))
+                },
+            }
+        })
+    }
+
+    /// Watch the window being mapped and unmapped yielding `true` when it is shown and `false` when
+    /// it is hidden e.g. iconified. The iterator ends when the window is destroyed.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// for mapped in win.watch_map_state() {
+    ///     println!("mapped: {}", mapped.unwrap());
+    /// }
+    /// ```
+    pub fn watch_map_state(&self) -> impl Iterator<Item = WmCtlResult<bool>> {
+        let id = self.id;
+        let mut selected = false;
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            if !selected {
+                selected = true;
+                if let Err(e) = WM().read().unwrap().select_window_events(id) {
+                    done = true;
+                    return Some(Err(e));
+                }
+            }
+            match wait_change(|wm| wm.poll_window_map_state(id)) {
+                Ok(Some(mapped)) => Some(Ok(mapped)),
+                Ok(None) => {
+                    done = true;
+                    None
+                },
+                Err(e) => {
+                    done = true;
+                    Some(Err(e))
+                },
+            }
+        })
+    }
+
+    /// Watch the window being moved between desktops yielding the new desktop numbered from 1 or
+    /// `None` when the window is made sticky. The iterator ends when the window is destroyed.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// for desktop in win.watch_desktop() {
+    ///     println!("desktop: {:?}", desktop.unwrap());
+    /// }
+    /// ```
+    pub fn watch_desktop(&self) -> impl Iterator<Item = WmCtlResult<Option<u32>>> {
+        let id = self.id;
+        let mut selected = false;
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            if !selected {
+                selected = true;
+                if let Err(e) = WM().read().unwrap().select_window_events(id) {
+                    done = true;
+                    return Some(Err(e));
+                }
+            }
+            match wait_change(|wm| wm.poll_window_desktop(id)) {
+                Ok(Some(desktop)) => Some(Ok(u32::try_from(desktop).ok())),
+                Ok(None) => {
+                    done = true;
+                    None
+                },
+                Err(e) => {
+                    done = true;
+                    Some(Err(e))
+                },
+            }
+        })
+    }
+
+    /// Track the window's geometry invoking the callback with the new geometry in root coordinates
+    /// each time the window is moved or resized. Blocks until the window is destroyed.
+    ///
+    /// ### Arguments
+    /// * `f` - callback to invoke with each new geometry
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.track_geometry(|g| println!("{}, {}, {}, {}", g.x, g.y, g.w, g.h)).unwrap();
+    /// ```
+    pub fn track_geometry(&self, mut f: impl FnMut(Geometry)) -> WmCtlResult<()> {
+        WM().read().unwrap().select_window_events(self.id)?;
+
+        // Lock is released while waiting and before invoking the callback so it can query the window
+        loop {
+            match wait_change(|wm| wm.poll_window_geometry(self.id))? {
+                Some(geometry) => f(geometry),
+                None => return Ok(()),
+            }
+        }
+    }
+
+    /// Get window GNOME border values added by GTK
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let (l, r, t, b) = win.gtk_borders();
+    /// ```
+    pub fn gtk_borders(&self) -> Border {
+        WM().read().unwrap().window_gtk_borders(self.id).unwrap_or_default()
+    }
+
+    /// Check if the window is viewable i.e. it is mapped and all of its ancestors are mapped
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.is_viewable().unwrap();
+    /// ```
+    pub fn is_viewable(&self) -> WmCtlResult<bool> {
+        WM().read().unwrap().window_is_viewable(self.id)
+    }
+
+    /// Get the window's visual depth in bits
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let depth = win.visual_depth().unwrap();
+    /// ```
+    pub fn visual_depth(&self) -> WmCtlResult<u8> {
+        WM().read().unwrap().window_visual_depth(self.id)
+    }
+
+    /// Check if the window uses a 32 bit ARGB visual and can therefore actually be transparent
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let argb = win.is_argb().unwrap();
+    /// ```
+    pub fn is_argb(&self) -> WmCtlResult<bool> {
+        WM().read().unwrap().window_is_argb(self.id)
+    }
+
+    /// Capture the window and encode it as a PNG scaled down to fit within the given maximum
+    /// dimension keeping the aspect ratio e.g. for window switcher previews. The window must be
+    /// viewable and obscured parts are only captured correctly when a compositor is running.
+    ///
+    /// ### Arguments
+    /// * `max_dim` - maximum width and height of the thumbnail
+    ///
+    /// ### Returns
+    /// * PNG encoded thumbnail
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// std::fs::write("thumbnail.png", win.thumbnail(256).unwrap()).unwrap();
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn thumbnail(&self, max_dim: u32) -> WmCtlResult<Vec<u8>> {
+        let (w, h, rgba) = WM().read().unwrap().window_image(self.id)?;
+        let (tw, th) = crate::png::thumbnail_size(w, h, max_dim);
+        let scaled = crate::png::scale_rgba(&rgba, w, h, tw, th);
+        Ok(crate::png::encode(&scaled, tw, th))
+    }
+
+    /// Get window mapped state
+    /// * doesn't return a valid state if all windows are included rather than just the managed ones
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let state = win.mapped().unwrap();
+    /// ```
+    pub fn mapped(&self) -> WmCtlResult<MapState> {
+        WM().read().unwrap().window_attributes(self.id)
+    }
+
+    /// Get all window properties generically
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.properties().unwrap();
+    /// ```
+    pub fn properties(&self) -> WmCtlResult<Vec<Property>> {
+        WM().read().unwrap().window_properties(self.id)
+    }
+
+    /// Map the window to the screen
+    /// * Windows are created in the unmapped state and must be mapped to be visible
+    /// * Unmapping the window will have the opposite effect of hidding the window
+    /// * Useful for new windows or dialogs that need to conditionally be visible
+    /// * It is much faster to hide and show and window rather than recreate it
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.map().unwrap();
+    /// ```
+    pub fn map(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().map_window(self.id)
+    }
+
+    /// Maximize the window both horizontally and vertically
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.maximize().unwrap();
+    /// ```
+    pub fn maximize(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().maximize_window(self.id)
+    }
+
+    /// Focus the window and bring it to the front of the stacking order
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.focus().unwrap();
+    /// ```
+    pub fn focus(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().focus_window(self.id)
+    }
+
+    /// Activate the window via a _NET_ACTIVE_WINDOW request to raise it and give it input focus.
+    /// When the window is on another desktop most window managers switch to that desktop, others
+    /// move the window to the current desktop or only mark it as demanding attention.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.activate().unwrap();
+    /// ```
+    pub fn activate(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().focus_window(self.id)
+    }
+
+    /// Check if the application owning the window is still responding by pinging it. This is more
+    /// reliable than checking the pid when deciding whether to offer to kill a hung application.
+    /// * Waits up to 2 seconds for the application to reply
+    ///
+    /// ### Returns
+    /// * `true` if the application replied in time or `false` if it appears hung
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// if !win.ping().unwrap() {
+    ///     win.kill().unwrap();
+    /// }
+    /// ```
+    pub fn ping(&self) -> WmCtlResult<bool> {
+        WM().read().unwrap().ping(self.id, Duration::from_secs(2))
+    }
+
+    /// Gracefully close the window giving the application a chance to save its state
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.close().unwrap();
+    /// ```
+    pub fn close(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().close_window(self.id)
+    }
+
+    /// Check if the window still exists i.e. it hasn't been destroyed
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// let exists = win.exists().unwrap();
+    /// ```
+    pub fn exists(&self) -> WmCtlResult<bool> {
+        WM().read().unwrap().window_exists(self.id)
+    }
+
+    /// Forcibly disconnect the application owning the window as done by `xkill`. This does not
+    /// give the application a chance to save its state and should only be used as a last resort
+    /// when a graceful close has been ignored.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.kill().unwrap();
+    /// ```
+    pub fn kill(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().kill_client(self.id)
+    }
+
+    /// Get the space the window reserves along the edges of the screen e.g. for a panel or dock
+    ///
+    /// ### Returns
+    /// * the reserved space or `None` if the window doesn't reserve any
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// if let Some(strut) = win.struts().unwrap() {
+    ///     println!("top: {}", strut.top);
+    /// }
+    /// ```
+    pub fn struts(&self) -> WmCtlResult<Option<Strut>> {
+        WM().read().unwrap().window_struts(self.id)
+    }
+
+    /// Reserve space along the edges of the screen for the window so that it acts as a panel or
+    /// dock and the window manager shrinks the work area for other windows accordingly
+    ///
+    /// ### Arguments
+    /// * `strut` - space to reserve along each edge
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.reserve_space(Strut { top: 30, top_end_x: 1919, ..Default::default() }).unwrap();
+    /// ```
+    pub fn reserve_space(&self, strut: Strut) -> WmCtlResult<()> {
+        WM().read().unwrap().set_strut_partial(self.id, strut)
+    }
+
+    /// Reparent the window into the given parent window at the given position relative to the
+    /// parent e.g. to embed it in another window.
+    /// * The window manager may stop managing a window that has been reparented away from it
+    /// * Reparent sequences should be done with the server grabbed to avoid racing the window manager
+    ///
+    /// ### Arguments
+    /// * `parent` - window to embed this window in
+    /// * `x` - x coordinate relative to the parent
+    /// * `y` - y coordinate relative to the parent
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.reparent_to(&window(67890), 0, 0).unwrap();
+    /// ```
+    pub fn reparent_to(&self, parent: &Window, x: i16, y: i16) -> WmCtlResult<()> {
+        WM().read().unwrap().reparent_window(self.id, parent.id, x, y)
+    }
+
+    /// Warp the pointer to the given position relative to the window's top left corner
+    ///
+    /// ### Arguments
+    /// * `rel_x` - x coordinate relative to the window's left edge
+    /// * `rel_y` - y coordinate relative to the window's top edge
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.warp_pointer_to(10, 10).unwrap();
+    /// ```
+    pub fn warp_pointer_to(&self, rel_x: i32, rel_y: i32) -> WmCtlResult<()> {
+        WM().read().unwrap().warp_pointer(self.id, rel_x, rel_y)
+    }
+
+    /// Warp the pointer to the center of the window
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.warp_pointer_center().unwrap();
+    /// ```
+    pub fn warp_pointer_center(&self) -> WmCtlResult<()> {
+        let (_, _, w, h) = self.geometry()?;
+        self.warp_pointer_to(w as i32 / 2, h as i32 / 2)
+    }
+
+    /// Raise the window to the top of the stacking order without focusing it
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.raise().unwrap();
+    /// ```
+    pub fn raise(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().raise_window(self.id)
+    }
+
+    /// Lower the window to the bottom of the stacking order
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.lower().unwrap();
+    /// ```
+    pub fn lower(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().lower_window(self.id)
+    }
+
+    /// Check if the window has a horizontally or vertically maximized
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.maximized()
+    /// ```
+    pub fn maximized(&self) -> bool {
+        self.state().is_ok_and(|states| states.contains(&State::MaxVert) || states.contains(&State::MaxHorz))
+    }
+
+    /// Remove the MaxVert and MaxHorz states
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.unmaximize().unwrap();
+    /// ```
+    pub fn unmaximize(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().unmaximize_window(self.id)
+    }
+
+    /// Toggle the window between maximized and unmaximized. The window's current state is read
+    /// first and if both MaxHorz and MaxVert are present the window is unmaximized otherwise it
+    /// is maximized. This is more reliable than `toggle_maximize_native()` as not all window
+    /// managers honor the toggle action for the combined maximize states.
+    ///
+    /// ### Returns
+    /// * `true` if the window was maximized, `false` if it was unmaximized
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.toggle_maximize().unwrap();
+    /// ```
+    pub fn toggle_maximize(&self) -> WmCtlResult<bool> {
+        let states = self.state()?;
+        if states.contains(&State::MaxHorz) && states.contains(&State::MaxVert) {
+            self.unmaximize()?;
+            Ok(false)
+        } else {
+            self.maximize()?;
+            Ok(true)
+        }
+    }
+
+    /// Toggle the window between maximized and unmaximized using the window manager's native
+    /// _NET_WM_STATE toggle action without first reading the window's state.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.toggle_maximize_native().unwrap();
+    /// ```
+    pub fn toggle_maximize_native(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().set_window_state(
+            self.id,
+            WINDOW_STATE_ACTION_TOGGLE,
+            &[State::MaxHorz, State::MaxVert],
+        )
+    }
+
+    /// Move the window to the given desktop or to all desktops if `DESKTOP_STICKY` is given. On
+    /// viewport based window managers the window is shifted into the given viewport cell instead.
+    ///
+    /// ### Arguments
+    /// * `desktop` - desktop to move the window to numbered from 1 or `DESKTOP_STICKY`
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.move_to_desktop(2).unwrap();
+    /// ```
+    pub fn move_to_desktop(&self, desktop: u32) -> WmCtlResult<()> {
+        WM().read().unwrap().move_window_to_desktop(self.id, desktop)
+    }
+
+    /// Place the window on all desktops i.e. move it to `DESKTOP_STICKY`
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.pin_to_all_desktops().unwrap();
+    /// ```
+    pub fn pin_to_all_desktops(&self) -> WmCtlResult<()> {
+        self.move_to_desktop(DESKTOP_STICKY)
+    }
+
+    /// Move the window to the given desktop then switch to that desktop so the window stays in
+    /// view. A sticky target only sticks the window as it is already visible on every desktop.
+    ///
+    /// ### Arguments
+    /// * `desktop` - desktop to move the window to numbered from 1 or `DESKTOP_STICKY`
+    /// * `activate` - activate the window once the desktop has been switched
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.move_to_desktop_and_follow(2, true).unwrap();
+    /// ```
+    pub fn move_to_desktop_and_follow(&self, desktop: u32, activate: bool) -> WmCtlResult<()> {
+        let wm = WM().read().unwrap();
+
+        // Move the window first so that it is already on the target desktop when the switch
+        // happens. Events are checked as they are sent so the window manager receives them in order.
+        wm.move_window_to_desktop(self.id, desktop)?;
+        if desktop == DESKTOP_STICKY {
+            return Ok(());
+        }
+        wm.set_active_desktop(desktop)?;
+        if activate {
+            wm.focus_window(self.id)?;
+        }
+        Ok(())
+    }
+
+    /// Move the window to the given desktop, wait for the window manager to have applied the move
+    /// and only then switch to the desktop and activate the window. Activating a window before the
+    /// move completes can otherwise bounce focus back to the old desktop.
+    ///
+    /// ### Arguments
+    /// * `desktop` - desktop to move the window to numbered from 1 or `DESKTOP_STICKY`
+    ///
+    /// ### Returns
+    /// * `true` if the window manager applied the move within a second, `false` if it was activated
+    ///   regardless
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.move_to_desktop_then_activate(3).unwrap();
+    /// ```
+    pub fn move_to_desktop_then_activate(&self, desktop: u32) -> WmCtlResult<bool> {
+        {
+            let wm = WM().read().unwrap();
+            wm.move_window_to_desktop(self.id, desktop)?;
+            wm.sync()?;
+        }
+
+        // The window manager applies the move asynchronously so wait until it is reported
+        let expected = if desktop == DESKTOP_STICKY { -1 } else { desktop as i32 };
+        let deadline = Instant::now() + Duration::from_secs(1);
+        let mut moved = self.effective_desktop()? == expected;
+        while !moved && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(20));
+            moved = self.effective_desktop()? == expected;
+        }
+        debug!("move_to_desktop_then_activate: id: {}, desktop: {}, moved: {}", self.id, desktop, moved);
+
+        let wm = WM().read().unwrap();
+        if desktop != DESKTOP_STICKY {
+            wm.set_active_desktop(desktop)?;
+        }
+        wm.focus_window(self.id)?;
+        Ok(moved)
+    }
+
+    /// Toggle the window in and out of fullscreen based on its current state
+    ///
+    /// ### Returns
+    /// * `true` if the window is now fullscreen, `false` if it no longer is
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.toggle_fullscreen().unwrap();
+    /// ```
+    pub fn toggle_fullscreen(&self) -> WmCtlResult<bool> {
+        let fullscreen = !self.state()?.contains(&State::Fullscreen);
+        if fullscreen {
+            self.fullscreen()?;
+        } else {
+            self.unfullscreen()?;
+        }
+        Ok(fullscreen)
+    }
+
+    /// Toggle the window in and out of fullscreen using the window manager's native _NET_WM_STATE
+    /// toggle action without first reading the window's state.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.toggle_fullscreen_native().unwrap();
+    /// ```
+    pub fn toggle_fullscreen_native(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().toggle_fullscreen_window(self.id)
+    }
+
+    /// Make the window fullscreen covering the entire monitor including any panels
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.fullscreen().unwrap();
+    /// ```
+    pub fn fullscreen(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().fullscreen_window(self.id)
+    }
+
+    /// Take the window out of fullscreen restoring its previous geometry
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.unfullscreen().unwrap();
+    /// ```
+    pub fn unfullscreen(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().unfullscreen_window(self.id)
+    }
+
+    /// Toggle the window between being on all desktops and only the active desktop. Window managers
+    /// express stickiness via either the sticky desktop value or the sticky state so both are set
+    /// and then read back to confirm the change.
+    ///
+    /// ### Returns
+    /// * `true` if the window is now sticky, `false` if it no longer is
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.toggle_sticky().unwrap();
+    /// ```
+    pub fn toggle_sticky(&self) -> WmCtlResult<bool> {
+        let sticky = !self.sticky();
+        {
+            let wm = WM().read().unwrap();
+            if sticky {
+                wm.move_window_to_desktop(self.id, DESKTOP_STICKY)?;
+                wm.set_window_state(self.id, WINDOW_STATE_ACTION_ADD, &[State::Sticky])?;
+            } else {
+                wm.set_window_state(self.id, WINDOW_STATE_ACTION_REMOVE, &[State::Sticky])?;
+                wm.move_window_to_desktop(self.id, wm.active_desktop()?)?;
+            }
+        }
+
+        // Changes are asynchronous so give the window manager a moment to apply them
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while self.sticky() != sticky && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        let sticky = self.sticky();
+        debug!("toggle_sticky: id: {}, sticky: {}", self.id, sticky);
+        Ok(sticky)
+    }
+
+    /// Check if the window is on all desktops via either the desktop value or the sticky state
+    fn sticky(&self) -> bool {
+        self.desktop().is_ok_and(|x| x == -1) || self.state().is_ok_and(|x| x.contains(&State::Sticky))
+    }
+
+    /// Add, remove or toggle the given window states then wait until the window manager has
+    /// actually applied them. State changes are asynchronous so querying the state right after
+    /// requesting a change may otherwise see the old state.
+    ///
+    /// ### Arguments
+    /// * `action` - WINDOW_STATE_ACTION_ADD, WINDOW_STATE_ACTION_REMOVE or WINDOW_STATE_ACTION_TOGGLE
+    /// * `states` - states to change
+    /// * `timeout` - how long to wait for the change to be applied
+    ///
+    /// ### Returns
+    /// * `true` if the states were applied in time or `false` if the timeout fired first
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.set_state_verified(WINDOW_STATE_ACTION_ADD, &[State::Fullscreen], Duration::from_secs(1)).unwrap();
+    /// ```
+    pub fn set_state_verified(
+        &self, action: WindowStateAction, states: &[State], timeout: Duration,
+    ) -> WmCtlResult<bool> {
+        // Toggled states are expected to end up the opposite of how they started
+        let before = self.state()?;
+        let expected = states
+            .iter()
+            .map(|x| match action {
+                WINDOW_STATE_ACTION_ADD => (x, true),
+                WINDOW_STATE_ACTION_REMOVE => (x, false),
+                _ => (x, !before.contains(x)),
+            })
+            .collect::<Vec<_>>();
+        WM().read().unwrap().set_window_state(self.id, action, states)?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            let current = self.state()?;
+            if expected.iter().all(|(x, present)| current.contains(x) == *present) {
+                debug!("set_state_verified: id: {}, states: {:?}, applied: true", self.id, states);
+                return Ok(true);
+            }
+            if Instant::now() >= deadline {
+                debug!("set_state_verified: id: {}, states: {:?}, applied: false", self.id, states);
+                return Ok(false);
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    /// Request the user's attention for the window by setting the demands attention state and
+    /// the ICCCM urgency hint. The request stays in effect until cleared.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.demand_attention().unwrap();
+    /// ```
+    pub fn demand_attention(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().set_window_attention(self.id, true)
+    }
+
+    /// Clear a previous request for the user's attention
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.clear_attention().unwrap();
+    /// ```
+    pub fn clear_attention(&self) -> WmCtlResult<()> {
+        WM().read().unwrap().set_window_attention(self.id, false)
+    }
+
+    /// Flash the window by requesting the user's attention for the given duration. No background
+    /// thread is spawned, instead the returned guard clears the request when dropped. Use the
+    /// guard's `wait()` to block until the duration has elapsed and then clear the request.
+    ///
+    /// ### Arguments
+    /// * `duration` - how long the window should demand attention for
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.flash(Duration::from_secs(3)).unwrap().wait().unwrap();
     /// ```
-    pub fn gtk_borders(&self) -> Border {
-        WM().read().unwrap().window_gtk_borders(self.id).unwrap_or(Border::default())
+    #[must_use = "attention is cleared when the guard is dropped"]
+    pub fn flash(&self, duration: Duration) -> WmCtlResult<AttentionGuard> {
+        self.demand_attention()?;
+        Ok(AttentionGuard {
+            id: self.id,
+            deadline: Instant::now() + duration,
+            cleared: false,
+        })
     }
 
-    /// Get window mapped state
-    /// * doesn't return a valid state if all windows are included rather than just the managed ones
+    /// Divide the work area of the monitor the window is on into a grid of `cols` x `rows` cells and
+    /// place the window to span the cells from (`x`, `y`) to (`x + w`, `y + h`). Borders are taken
+    /// into account so that windows placed in adjacent cells tile flush against each other.
+    /// * Returns InvalidMonitor if the window isn't on any monitor
+    ///
+    /// ### Arguments
+    /// * `cols` - number of columns to divide the work area into
+    /// * `rows` - number of rows to divide the work area into
+    /// * `x` - column of the first cell to span starting from 0
+    /// * `y` - row of the first cell to span starting from 0
+    /// * `w` - number of columns to span
+    /// * `h` - number of rows to span
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let win = window(12345);
-    /// let state = win.mapped().unwrap();
+    /// win.grid_place(3, 2, 1, 0, 2, 1).unwrap();
     /// ```
-    pub fn mapped(&self) -> WmCtlResult<MapState> {
-        WM().read().unwrap().window_attributes(self.id)
+    pub fn grid_place(&self, cols: u32, rows: u32, x: u32, y: u32, w: u32, h: u32) -> WmCtlResult<()> {
+        let layout = WM().read().unwrap().screen_layout()?;
+        let area = layout.monitor_of_window(self)?.ok_or(WmCtlError::InvalidMonitor(0))?.work_area;
+        let (x, y, w, h) = translate_grid(&self.borders(), &self.gtk_borders(), &area, cols, rows, x, y, w, h)?;
+
+        // Unmaximize to shape and position the window correctly
+        if self.maximized() {
+            self.unmaximize()?;
+        }
+        debug!("grid_place: {}, {}, {}, {}", x, y, w, h);
+        WM().read().unwrap().move_resize_window(self.id, None, Some(x), Some(y), Some(w), Some(h))
     }
 
-    /// Get all window properties generically
+    /// Get the visible windows higher in the stacking order that overlap this window. Windows on
+    /// other desktops are ignored as they can't obscure this window.
+    ///
+    /// ### Returns
+    /// * overlapping windows ordered from the top of the stack down
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let win = window(12345);
-    /// win.properties().unwrap();
+    /// let occluded = !win.windows_above().unwrap().is_empty();
     /// ```
-    pub fn properties(&self) -> WmCtlResult<Vec<Property>> {
-        WM().read().unwrap().window_properties(self.id)
+    pub fn windows_above(&self) -> WmCtlResult<Vec<Window>> {
+        let stack = crate::windows_by_stack_order()?;
+        match stack.iter().position(|x| x.id == self.id) {
+            Some(i) => self.overlapping(&stack[..i]),
+            None => Ok(vec![]),
+        }
     }
 
-    /// Map the window to the screen
-    /// * Windows are created in the unmapped state and must be mapped to be visible
-    /// * Unmapping the window will have the opposite effect of hidding the window
-    /// * Useful for new windows or dialogs that need to conditionally be visible
-    /// * It is much faster to hide and show and window rather than recreate it
+    /// Get the visible windows lower in the stacking order that overlap this window
+    ///
+    /// ### Returns
+    /// * overlapping windows ordered from the top of the stack down
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let win = window(12345);
-    /// win.map().unwrap();
+    /// let below = win.windows_below().unwrap();
     /// ```
-    pub fn map(&self) -> WmCtlResult<()> {
-        WM().read().unwrap().map_window(self.id)
+    pub fn windows_below(&self) -> WmCtlResult<Vec<Window>> {
+        let stack = crate::windows_by_stack_order()?;
+        match stack.iter().position(|x| x.id == self.id) {
+            Some(i) => self.overlapping(&stack[i + 1..]),
+            None => Ok(vec![]),
+        }
     }
 
-    /// Maximize the window both horizontally and vertically
+    // Filter the given windows down to those visible on the same desktop and overlapping this one
+    fn overlapping(&self, windows: &[Window]) -> WmCtlResult<Vec<Window>> {
+        let desktop = self.desktop()?;
+        let geometry = self.visual_geometry()?;
+        let mut overlapping = vec![];
+        for win in windows {
+            let other = win.desktop()?;
+            if other != desktop && other != -1 && desktop != -1 {
+                continue;
+            }
+            if win.is_viewable()? && intersects(geometry, win.visual_geometry()?) {
+                overlapping.push(win.clone());
+            }
+        }
+        Ok(overlapping)
+    }
+
+    /// Move and resize the window then report the geometry the window manager actually applied
+    /// which may differ from the request due to size hints, struts or gravity.
+    ///
+    /// ### Arguments
+    /// * `x` - x coordinate to move the window to or None for no change
+    /// * `y` - y coordinate to move the window to or None for no change
+    /// * `w` - width to resize the window to or None for no change
+    /// * `h` - height to resize the window to or None for no change
+    ///
+    /// ### Returns
+    /// * the window's geometry after the window manager has acted on the request
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let win = window(12345);
-    /// win.maximize().unwrap();
+    /// let geometry = win.move_resize_reporting(Some(0), Some(0), Some(500), Some(500)).unwrap();
     /// ```
-    pub fn maximize(&self) -> WmCtlResult<()> {
-        WM().read().unwrap().maximize_window(self.id)
+    pub fn move_resize_reporting(
+        &self, x: Option<i32>, y: Option<i32>, w: Option<u32>, h: Option<u32>,
+    ) -> WmCtlResult<Geometry> {
+        let wm = WM().read().unwrap();
+        let before = Geometry::from(wm.window_geometry(self.id)?);
+        wm.move_resize_window(self.id, None, x, y, w, h)?;
+
+        // The window manager acts on the request asynchronously so give it a moment to settle
+        let mut after = before;
+        for _ in 0..10 {
+            std::thread::sleep(Duration::from_millis(20));
+            after = Geometry::from(wm.window_geometry(self.id)?);
+            if after != before {
+                break;
+            }
+        }
+        debug!("move_resize_reporting: before: {:?}, after: {:?}", before, after);
+        Ok(after)
     }
 
-    /// Focus the window and bring it to the front of the stacking order
+    /// Scale the window's position and size from the old dimensions to the new ones so that the
+    /// window keeps its relative position after a resolution change. On multi-monitor setups the
+    /// dimensions are those of the monitor the window is on and the window is scaled relative to
+    /// that monitor's origin such that it stays on the same monitor.
+    ///
+    /// ### Arguments
+    /// * `old` - monitor (width, height) the window was positioned for
+    /// * `new` - monitor (width, height) to reposition the window for
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let win = window(12345);
-    /// win.focus().unwrap();
+    /// win.reposition_proportionally((3840, 2160), (1920, 1080)).unwrap();
     /// ```
-    pub fn focus(&self) -> WmCtlResult<()> {
-        WM().read().unwrap().focus_window(self.id)
+    pub fn reposition_proportionally(&self, old: (u32, u32), new: (u32, u32)) -> WmCtlResult<()> {
+        let geometry = self.geometry()?;
+        let layout = WM().read().unwrap().screen_layout()?;
+        let origin = match layout.monitor_overlapping(&Geometry::from(geometry)) {
+            Some(monitor) if layout.monitors.len() > 1 => (monitor.x, monitor.y),
+            _ => (0, 0),
+        };
+        let (x, y, w, h) = translate_proportional(geometry, origin, old, new);
+        if self.maximized() {
+            self.unmaximize()?;
+        }
+        debug!("reposition_proportionally: {}, {}, {}, {}", x, y, w, h);
+        WM().read().unwrap().move_resize_window(self.id, None, Some(x), Some(y), Some(w), Some(h))
     }
 
-    /// Check if the window has a horizontally or vertically maximized
+    /// Grow or shrink the window by a percentage of its current size keeping the top left corner
+    /// anchored. The result respects the window's size hints and is limited to the work area of
+    /// the monitor the window is on.
+    ///
+    /// ### Arguments
+    /// * `dw_pct` - percentage to grow the width by e.g. 10.0 or shrink if negative
+    /// * `dh_pct` - percentage to grow the height by e.g. 10.0 or shrink if negative
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let win = window(12345);
-    /// win.maximized()
+    /// win.resize_by_percent(10.0, 10.0).unwrap();
     /// ```
-    pub fn maximized(&self) -> bool {
-        self.state().is_ok_and(|states| states.contains(&State::MaxVert) || states.contains(&State::MaxHorz))
+    pub fn resize_by_percent(&self, dw_pct: f64, dh_pct: f64) -> WmCtlResult<()> {
+        let (_, _, w, h) = self.geometry()?;
+        let hints = self.normal_hints().unwrap_or_default();
+        let (border, csd_border) = (self.borders(), self.gtk_borders());
+
+        // Largest the window can be on its monitor taking the decorations into account
+        let area = self.monitor()?.work_area;
+        let max = if csd_border.any() {
+            Rect::new(area.w + csd_border.w(), area.h + csd_border.h())
+        } else {
+            Rect::new(area.w.saturating_sub(border.w()), area.h.saturating_sub(border.h()))
+        };
+
+        let (w, h) = translate_resize_percent(&Rect::new(w, h), &hints, &max, dw_pct, dh_pct);
+        debug!("resize_by_percent: id: {}, w: {}, h: {}", self.id, w, h);
+        WM().read().unwrap().move_resize_window(self.id, None, None, None, Some(w), Some(h))
     }
 
-    /// Remove the MaxVert and MaxHorz states
+    /// Move the window such that its visible top left corner including any decorations lands
+    /// exactly at the given screen coordinate. The window's frame extents, client side decorations
+    /// and gravity are taken into account so the caller doesn't need to compensate for them.
+    ///
+    /// ### Arguments
+    /// * `x` - x coordinate for the visible left edge of the window
+    /// * `y` - y coordinate for the visible top edge of the window
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let win = window(12345);
-    /// win.unmaximize().unwrap();
+    /// win.move_visible_to(0, 0).unwrap();
     /// ```
-    pub fn unmaximize(&self) -> WmCtlResult<()> {
-        WM().read().unwrap().unmaximize_window(self.id)
+    pub fn move_visible_to(&self, x: i32, y: i32) -> WmCtlResult<()> {
+        let gravity = self.gravity();
+        let (x, y) = translate_visible(x, y, &self.borders(), &self.gtk_borders(), &gravity);
+        debug!("move_visible_to: {}, {}, gravity: {}", x, y, gravity);
+        WM().read().unwrap().move_resize_window(self.id, None, Some(x), Some(y), None, None)
+    }
+
+    /// Restore the window to the given saved arrangement. States that override the geometry like
+    /// maximized are cleared first so the position and size stick, then the saved states are
+    /// reapplied on top.
+    ///
+    /// ### Arguments
+    /// * `entry` - saved arrangement of the window
+    pub(crate) fn restore(&self, entry: &LayoutEntry) -> WmCtlResult<()> {
+        let desktops = WM().read().unwrap().desktop_position()?.1;
+        if entry.desktop == -1 {
+            self.move_to_desktop(DESKTOP_STICKY)?;
+        } else if entry.desktop > 0 && entry.desktop as u32 <= desktops {
+            self.move_to_desktop(entry.desktop as u32)?;
+        }
+
+        let (x, y) = translate_visible(entry.x, entry.y, &self.borders(), &self.gtk_borders(), &self.gravity());
+        let wm = WM().read().unwrap();
+        wm.set_window_state(
+            self.id,
+            WINDOW_STATE_ACTION_REMOVE,
+            &[State::Fullscreen, State::MaxHorz, State::MaxVert],
+        )?;
+        wm.move_resize_window(self.id, None, Some(x), Some(y), Some(entry.w), Some(entry.h))?;
+
+        let states = entry.states.iter().filter(|x| is_restorable(x)).cloned().collect::<Vec<_>>();
+        if !states.is_empty() {
+            wm.set_window_state(self.id, WINDOW_STATE_ACTION_ADD, &states)?;
+        }
+        Ok(())
+    }
+
+    /// Get the window's gravity from its size hints defaulting to NorthWest
+    fn gravity(&self) -> Gravity {
+        match self.normal_hints() {
+            Ok(hints) if hints.has_win_gravity() => Gravity::from(hints.win_gravity),
+            _ => Gravity::NorthWest,
+        }
     }
 
     /// Queue the shape the window should be. This will not take effect until the place() method is called.
@@ -269,9 +1789,9 @@ impl Window {
     /// * `shape` - pre-defined shape to manipulate the window into
     ///
     /// ### Examples
-    /// ```
+    /// ```ignore
     /// use libwmctl::prelude::*;
-    /// window(12345).shape(WinShape::Large).place().unwrap();
+    /// window(12345).shape(Shape::Large).place().unwrap();
     /// ```
     pub fn shape(mut self, shape: Shape) -> Self {
         self.shape = Some(shape);
@@ -285,9 +1805,9 @@ impl Window {
     /// * `pos` - pre-defined position to move the window to
     ///
     /// ### Examples
-    /// ```
+    /// ```ignore
     /// use libwmctl::prelude::*;
-    /// window(12345).pos(WinPosition::Right).place().unwrap();
+    /// window(12345).pos(Position::Right).place().unwrap();
     /// ```
     pub fn pos(mut self, pos: Position) -> Self {
         self.pos = Some(pos);
@@ -354,6 +1874,110 @@ impl Window {
         debug!("place: {:?}, {:?}, {}, {}", x, y, w, h);
         wm.move_resize_window(self.id, gravity, x, y, sw, sh)
     }
+
+    /// Snap the window to the given half of the work area of the monitor it is on. The window is
+    /// unmaximized first and its frame is accounted for so that it sits flush against the edge.
+    ///
+    /// ### Arguments
+    /// * `edge` - side of the monitor to snap the window against
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let win = window(12345);
+    /// win.snap(Edge::Left).unwrap();
+    /// ```
+    pub fn snap(&self, edge: Edge) -> WmCtlResult<()> {
+        self.snap_on(&self.monitor()?, edge)
+    }
+
+    /// Snap the window to the given half of the work area of the given monitor
+    ///
+    /// ### Arguments
+    /// * `monitor` - monitor to snap the window on
+    /// * `edge` - side of the monitor to snap the window against
+    pub(crate) fn snap_on(&self, monitor: &Monitor, edge: Edge) -> WmCtlResult<()> {
+        if self.maximized() {
+            self.unmaximize()?;
+        }
+        let (x, y, w, h) =
+            translate_snap(&monitor.work_area, &self.borders(), &self.gtk_borders(), &self.gravity(), edge);
+        debug!("snap: id: {}, edge: {}, monitor: {}, {}, {}, {}, {}", self.id, edge, monitor.name, x, y, w, h);
+        WM().read().unwrap().move_resize_window(self.id, None, Some(x), Some(y), Some(w), Some(h))
+    }
+
+    /// Get the monitor showing the most of the window falling back on the primary monitor
+    fn monitor(&self) -> WmCtlResult<Monitor> {
+        let layout = WM().read().unwrap().screen_layout()?;
+        let geometry = Geometry::from(self.visual_geometry()?);
+        let monitor = layout
+            .monitor_overlapping(&geometry)
+            .or_else(|| layout.primary())
+            .ok_or(WmCtlError::InvalidMonitor(0))?;
+        Ok(monitor.clone())
+    }
+}
+
+/// AttentionGuard is returned by `Window::flash()` and clears the attention request for the
+/// window when dropped or when its duration has elapsed via `wait()`.
+#[must_use = "attention is cleared when the guard is dropped"]
+pub struct AttentionGuard {
+    id: u32,
+    deadline: Instant,
+    cleared: bool,
+}
+
+impl AttentionGuard {
+    /// Check if the flash duration has elapsed
+    pub fn expired(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+
+    /// Block until the flash duration has elapsed then clear the attention request
+    pub fn wait(mut self) -> WmCtlResult<()> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if !remaining.is_zero() {
+            std::thread::sleep(remaining);
+        }
+        self.clear()
+    }
+
+    /// Clear the attention request now regardless of the remaining duration
+    pub fn clear(&mut self) -> WmCtlResult<()> {
+        if !self.cleared {
+            self.cleared = true;
+            WM().read().unwrap().set_window_attention(self.id, false)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for AttentionGuard {
+    fn drop(&mut self) {
+        let _ = self.clear();
+    }
+}
+
+// How often the window watchers check for a change
+const WATCH_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Wait for the window to change releasing the window manager lock between checks such that other
+/// callers aren't blocked while waiting
+///
+/// ### Arguments
+/// * `poll` - check for the change without blocking
+///
+/// ### Returns
+/// * the change or `None` if the window was destroyed
+fn wait_change<T>(poll: impl Fn(&WinMgr) -> WmCtlResult<WindowChange<T>>) -> WmCtlResult<Option<T>> {
+    loop {
+        let change = poll(&WM().read().unwrap())?;
+        match change {
+            WindowChange::Changed(x) => return Ok(Some(x)),
+            WindowChange::Destroyed => return Ok(None),
+            WindowChange::Pending => std::thread::sleep(WATCH_INTERVAL),
+        }
+    }
 }
 
 /// Translate position enum values into (x, y) cordinates but takes no direct action on the window.
@@ -588,10 +2212,487 @@ fn translate_shape(
     })
 }
 
+/// Scale the given (x, y, w, h) geometry from the old monitor dimensions to the new ones keeping
+/// the monitor's origin in place
+///
+/// ### Arguments
+/// * `geometry` - Window's current (x, y, w, h)
+/// * `origin` - top left (x, y) of the monitor the geometry is on
+/// * `old` - monitor (width, height) the geometry is relative to
+/// * `new` - monitor (width, height) to scale the geometry to
+///
+/// ### Returns
+/// * `(x, y, w, h)` scaled geometry
+fn translate_proportional(
+    geometry: (i32, i32, u32, u32), origin: (i32, i32), old: (u32, u32), new: (u32, u32),
+) -> (i32, i32, u32, u32) {
+    let sx = new.0 as f64 / old.0.max(1) as f64;
+    let sy = new.1 as f64 / old.1.max(1) as f64;
+    let (x, y, w, h) = geometry;
+    let (dx, dy) = (x as f64 - origin.0 as f64, y as f64 - origin.1 as f64);
+    (
+        origin.0 + (dx * sx).round() as i32,
+        origin.1 + (dy * sy).round() as i32,
+        (w as f64 * sx).round() as u32,
+        (h as f64 * sy).round() as u32,
+    )
+}
+
+/// Translate the coordinate for the visible top left corner of the window into the coordinate to
+/// request from the window manager but takes no direct action on the window.
+///
+/// ### Arguments
+/// * `x` - x coordinate for the visible left edge of the window
+/// * `y` - y coordinate for the visible top edge of the window
+/// * `border` - Window's border left, right, top, and bottom
+/// * `csd_border` - Client side border left, right, top, and bottom
+/// * `gravity` - Window's gravity which determines the reference point of the request
+///
+/// ### Returns
+/// * `(x, y)` coordinate to request
+pub(crate) fn translate_visible(
+    x: i32, y: i32, border: &Border, csd_border: &Border, gravity: &Gravity,
+) -> (i32, i32) {
+    if csd_border.any() {
+        // CSD windows draw shadows outside of the visible area as part of the window
+        (x - csd_border.l as i32, y - csd_border.t as i32)
+    } else if *gravity == Gravity::Static {
+        // Static gravity positions the client area so the frame needs to be accounted for
+        (x + border.l as i32, y + border.t as i32)
+    } else {
+        // NorthWest gravity positions the frame's top left corner
+        (x, y)
+    }
+}
+
+/// Translate the percentage change into the new (w, h) of the window but takes no direct action on
+/// the window. The result is limited to the given max size and then constrained by the size hints.
+///
+/// ### Arguments
+/// * `size` - Window's current width and height
+/// * `hints` - Window's size hints
+/// * `max` - Largest width and height the window should be e.g. to fit the work area
+/// * `dw_pct` - percentage to grow the width by or shrink if negative
+/// * `dh_pct` - percentage to grow the height by or shrink if negative
+///
+/// ### Returns
+/// * `(w, h)` size to request
+pub(crate) fn translate_resize_percent(
+    size: &Rect, hints: &SizeHints, max: &Rect, dw_pct: f64, dh_pct: f64,
+) -> (u32, u32) {
+    let scale = |x: u32, pct: f64, max: u32| ((x as f64 * (1.0 + pct / 100.0)).round().max(1.0) as u32).min(max);
+    hints.constrain(scale(size.w, dw_pct, max.w), scale(size.h, dh_pct, max.h))
+}
+
+/// Translate the edge into the (x, y, w, h) to request from the window manager such that the
+/// window fills that half of the monitor's work area but takes no direct action on the window.
+/// The work area is the part of the monitor not reserved by panels. Window should already be
+/// unmaximized before calling this.
+///
+/// ### Arguments
+/// * `area` - Monitor's work area to snap the window within
+/// * `border` - Window's border left, right, top, and bottom
+/// * `csd_border` - Client side border left, right, top, and bottom
+/// * `gravity` - Window's gravity which determines the reference point of the request
+/// * `edge` - Side of the monitor to snap against
+///
+/// ### Returns
+/// * `(x, y, w, h)` geometry to request
+pub(crate) fn translate_snap(
+    area: &Geometry, border: &Border, csd_border: &Border, gravity: &Gravity, edge: Edge,
+) -> (i32, i32, u32, u32) {
+    // Visible area of the monitor not covered by panels
+    let Geometry { x: l, y: t, w, h } = *area;
+
+    // Visible half of the area to fill
+    let (x, y, w, h) = match edge {
+        Edge::Left => (l, t, w / 2, h),
+        Edge::Right => (l + (w / 2) as i32, t, w - w / 2, h),
+        Edge::Top => (l, t, w, h / 2),
+        Edge::Bottom => (l, t + (h / 2) as i32, w, h - h / 2),
+    };
+
+    // CSD shadows are drawn outside the visible area while the window manager's frame is added on
+    let (w, h) = if csd_border.any() {
+        (w + csd_border.w(), h + csd_border.h())
+    } else {
+        (w.saturating_sub(border.w()), h.saturating_sub(border.h()))
+    };
+    let (x, y) = translate_visible(x, y, border, csd_border, gravity);
+    (x, y, w, h)
+}
+
+/// Translate the pixel delta into the visible (x, y) to move the window to but takes no direct
+/// action on the window. The result is clamped so that at least part of the window remains within
+/// the given bounds and can still be grabbed.
+///
+/// ### Arguments
+/// * `geometry` - Window's visual geometry
+/// * `bounds` - Area the window should remain partially within e.g. the combined screen
+/// * `dx` - pixels to move the window right or left if negative
+/// * `dy` - pixels to move the window down or up if negative
+///
+/// ### Returns
+/// * `(x, y)` visible coordinate to move the window to
+pub(crate) fn translate_nudge(geometry: &Geometry, bounds: &Geometry, dx: i32, dy: i32) -> (i32, i32) {
+    // Amount of the window to keep within the bounds
+    const MIN_VISIBLE: u32 = 50;
+    let vw = geometry.w.min(MIN_VISIBLE) as i64;
+    let vh = geometry.h.min(MIN_VISIBLE) as i64;
+
+    let clamp = |pos: i32, delta: i32, size: u32, start: i32, len: u32, visible: i64| {
+        let lo = start as i64 - size as i64 + visible;
+        let hi = (start as i64 + len as i64 - visible).max(lo);
+        (pos as i64 + delta as i64).clamp(lo, hi) as i32
+    };
+    (
+        clamp(geometry.x, dx, geometry.w, bounds.x, bounds.w, vw),
+        clamp(geometry.y, dy, geometry.h, bounds.y, bounds.h, vh),
+    )
+}
+
+/// Translate the window's position on one monitor into the equivalent position on another monitor
+/// but takes no direct action on the window. The offset within the monitor is scaled by the
+/// difference in monitor size and clamped so the window stays on the target monitor where it fits.
+///
+/// ### Arguments
+/// * `geometry` - Window's visual geometry
+/// * `from` - monitor the window is currently on
+/// * `to` - monitor to move the window to
+///
+/// ### Returns
+/// * `(x, y)` visible coordinate to move the window to
+pub(crate) fn translate_to_monitor(geometry: &Geometry, from: &Monitor, to: &Monitor) -> (i32, i32) {
+    let scale = |pos: i32, size: u32, from_start: i32, from_len: u32, to_start: i32, to_len: u32| {
+        let offset = (pos as i64 - from_start as i64) * to_len as i64 / from_len.max(1) as i64;
+        let hi = (to_len as i64 - size as i64).max(0);
+        (to_start as i64 + offset.clamp(0, hi)) as i32
+    };
+    (
+        scale(geometry.x, geometry.w, from.x, from.w, to.x, to.w),
+        scale(geometry.y, geometry.h, from.y, from.h, to.y, to.h),
+    )
+}
+
+/// Get the hostname of this machine from the kernel falling back on /etc/hostname
+fn local_hostname() -> Option<String> {
+    ["/proc/sys/kernel/hostname", "/etc/hostname"]
+        .iter()
+        .filter_map(|x| std::fs::read_to_string(x).ok())
+        .map(|x| x.trim().to_owned())
+        .find(|x| !x.is_empty())
+}
+
+/// Check if the two hostnames refer to the same machine ignoring case and treating a short name
+/// as the same as its fully qualified name
+fn same_host(a: &str, b: &str) -> bool {
+    let short = |x: &str| x.split('.').next().unwrap_or_default().to_lowercase();
+    a.eq_ignore_ascii_case(b) || ((!a.contains('.') || !b.contains('.')) && short(a) == short(b))
+}
+
+/// Pick the opacity to toggle to from the current opacity. Opacity is stored with limited
+/// precision so values within a small tolerance are considered the same.
+///
+/// ### Arguments
+/// * `current` - window's current opacity
+/// * `a` - first opacity to toggle between
+/// * `b` - second opacity to toggle between
+fn translate_opacity_toggle(current: f64, a: f64, b: f64) -> f64 {
+    if (current - a).abs() < 0.01 {
+        b
+    } else {
+        a
+    }
+}
+
+/// Check if the two (x, y, w, h) rectangles overlap. Rectangles that only share an edge don't.
+fn intersects(a: (i32, i32, u32, u32), b: (i32, i32, u32, u32)) -> bool {
+    let (ax, ay, aw, ah) = (a.0 as i64, a.1 as i64, a.2 as i64, a.3 as i64);
+    let (bx, by, bw, bh) = (b.0 as i64, b.1 as i64, b.2 as i64, b.3 as i64);
+    ax < bx + bw && bx < ax + aw && ay < by + bh && by < ay + ah
+}
+
+/// Translate a span of cells in a grid dividing the work area into a window (x, y, w, h) but takes
+/// no direct action on the window. Cell edges are shared by adjacent cells and borders are taken
+/// into account such that windows placed in adjacent cells tile flush against each other.
+///
+/// ### Arguments
+/// * `border` - Window's border left, right, top, and bottom
+/// * `csd_border` - Client side border left, right, top, and bottom
+/// * `area` - Work area of the monitor to divide into cells
+/// * `cols` - number of columns to divide the work area into
+/// * `rows` - number of rows to divide the work area into
+/// * `x` - column of the first cell to span
+/// * `y` - row of the first cell to span
+/// * `w` - number of columns to span
+/// * `h` - number of rows to span
+///
+/// ### Returns
+/// * `(x, y, w, h)` geometry to apply to the window
+#[allow(clippy::too_many_arguments)]
+fn translate_grid(
+    border: &Border, csd_border: &Border, area: &Geometry, cols: u32, rows: u32, x: u32, y: u32, w: u32, h: u32,
+) -> WmCtlResult<(i32, i32, u32, u32)> {
+    if cols == 0
+        || rows == 0
+        || w == 0
+        || h == 0
+        || x.checked_add(w).is_none_or(|x| x > cols)
+        || y.checked_add(h).is_none_or(|y| y > rows)
+    {
+        return Err(
+            WmCtlError::InvalidGrid(format!("{}x{} cells at {},{} in {}x{}", w, h, x, y, cols, rows)).into()
+        );
+    }
+
+    // Edges are calculated from the work area for each cell rather than multiplying out a single
+    // cell size so that rounding never leaves a gap between adjacent cells.
+    let left = (area.w as u64 * x as u64 / cols as u64) as u32;
+    let right = (area.w as u64 * (x + w) as u64 / cols as u64) as u32;
+    let top = (area.h as u64 * y as u64 / rows as u64) as u32;
+    let bottom = (area.h as u64 * (y + h) as u64 / rows as u64) as u32;
+    let (cw, ch) = (right - left, bottom - top);
+    let (left, top) = (area.x + left as i32, area.y + top as i32);
+
+    // Same border handling as for shapes and positions, regular windows have the borders added on
+    // by the window manager while CSD windows draw them as part of the window.
+    Ok(if csd_border.any() {
+        (left - csd_border.l as i32, top - csd_border.t as i32, cw + csd_border.w(), ch + csd_border.h())
+    } else {
+        (left, top, cw.saturating_sub(border.w()), ch.saturating_sub(border.h()))
+    })
+}
+
+/// Translate a cascade of the given number of windows into (x, y, w, h) geometries stepped
+/// diagonally by the given offsets from the work area origin. Each window is sized to 60% of the
+/// work area and positions are clamped so that later windows stay within the work area.
+///
+/// ### Arguments
+/// * `count` - number of windows to cascade
+/// * `offset_x` - horizontal step between windows
+/// * `offset_y` - vertical step between windows
+/// * `area` - Monitor's work area to cascade the windows within
+///
+/// ### Returns
+/// * `(x, y, w, h)` geometry for each window in cascade order
+pub(crate) fn translate_cascade(
+    count: usize, offset_x: i32, offset_y: i32, area: &Geometry,
+) -> Vec<(i32, i32, u32, u32)> {
+    let w = (area.w as f32 * 0.6) as u32;
+    let h = (area.h as f32 * 0.6) as u32;
+    let (max_x, max_y) = ((area.w - w) as i32, (area.h - h) as i32);
+    (0..count as i32)
+        .map(|i| (area.x + (i * offset_x).clamp(0, max_x), area.y + (i * offset_y).clamp(0, max_y), w, h))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_translate_proportional() {
+        // Halving the resolution halves everything
+        let geometry = translate_proportional((1920, 1080, 1280, 720), (0, 0), (3840, 2160), (1920, 1080));
+        assert_eq!(geometry, (960, 540, 640, 360));
+
+        // Changing aspect ratio scales each axis separately
+        let geometry = translate_proportional((100, 100, 800, 600), (0, 0), (1920, 1080), (2560, 1440));
+        assert_eq!(geometry, (133, 133, 1067, 800));
+
+        // Negative positions are preserved
+        let geometry = translate_proportional((-10, -20, 100, 100), (0, 0), (1000, 1000), (2000, 2000));
+        assert_eq!(geometry, (-20, -40, 200, 200));
+    }
+
+    #[test]
+    fn test_translate_proportional_monitors() {
+        let layout = ScreenLayout::new(vec![
+            Monitor::new("DP-1", true, 0, 0, 1920, 1080),
+            Monitor::new("DP-2", false, 1920, 0, 3840, 2160),
+        ]);
+
+        // A window on the second monitor is scaled against that monitor and stays on it
+        let geometry = (1920 + 960, 540, 1280, 720);
+        let monitor = layout.monitor_overlapping(&Geometry::from(geometry)).unwrap();
+        assert_eq!(monitor.name, "DP-2");
+        let origin = (monitor.x, monitor.y);
+        assert_eq!(translate_proportional(geometry, origin, (3840, 2160), (1920, 1080)), (2400, 270, 640, 360));
+    }
+
+    #[test]
+    fn test_translate_visible() {
+        let borders = Border::new(5, 5, 28, 5);
+        let csd = Border::default();
+        assert_eq!(translate_visible(100, 100, &borders, &csd, &Gravity::NorthWest), (100, 100));
+        assert_eq!(translate_visible(100, 100, &borders, &csd, &Gravity::Static), (105, 128));
+
+        // CSD shadows are always offset
+        let csd = Border::new(10, 10, 8, 12);
+        assert_eq!(translate_visible(0, 0, &borders, &csd, &Gravity::NorthWest), (-10, -8));
+        assert_eq!(translate_visible(0, 0, &borders, &csd, &Gravity::Static), (-10, -8));
+    }
+
+    #[test]
+    fn test_translate_snap() {
+        let left = Geometry::new(0, 0, 1920, 1040);
+        let right = Geometry::new(1920, 0, 1920, 1040);
+        let borders = Border::new(5, 5, 28, 5);
+        let csd = Border::default();
+        let nw = Gravity::NorthWest;
+        assert_eq!(translate_snap(&left, &borders, &csd, &nw, Edge::Left), (0, 0, 950, 1007));
+        assert_eq!(translate_snap(&left, &borders, &csd, &nw, Edge::Right), (960, 0, 950, 1007));
+        assert_eq!(translate_snap(&right, &borders, &csd, &nw, Edge::Top), (1920, 0, 1910, 487));
+        assert_eq!(translate_snap(&right, &borders, &csd, &nw, Edge::Bottom), (1920, 520, 1910, 487));
+
+        // Left dock and top panel reserving space on the monitor
+        let docked = Geometry::new(48, 30, 1872, 1050);
+        assert_eq!(translate_snap(&docked, &borders, &csd, &nw, Edge::Left), (48, 30, 926, 1017));
+        assert_eq!(translate_snap(&docked, &borders, &csd, &nw, Edge::Right), (984, 30, 926, 1017));
+        assert_eq!(translate_snap(&docked, &borders, &csd, &nw, Edge::Top), (48, 30, 1862, 492));
+
+        // CSD shadows extend past the edge
+        let csd = Border::new(10, 10, 8, 12);
+        assert_eq!(translate_snap(&left, &borders, &csd, &nw, Edge::Left), (-10, -8, 980, 1060));
+    }
+
+    #[test]
+    fn test_translate_nudge() {
+        let bounds = Geometry::new(0, 0, 1920, 1080);
+        let geometry = Geometry::new(100, 100, 800, 600);
+        assert_eq!(translate_nudge(&geometry, &bounds, 10, -10), (110, 90));
+
+        // At least part of the window stays on screen
+        assert_eq!(translate_nudge(&geometry, &bounds, -5000, 0), (-750, 100));
+        assert_eq!(translate_nudge(&geometry, &bounds, 5000, 5000), (1870, 1030));
+
+        // Small windows are kept fully within reach
+        let geometry = Geometry::new(0, 0, 20, 20);
+        assert_eq!(translate_nudge(&geometry, &bounds, -100, -100), (0, 0));
+    }
+
+    #[test]
+    fn test_same_host() {
+        assert!(same_host("box", "box"));
+        assert!(same_host("Box", "box.example.com"));
+        assert!(same_host("box.example.com", "BOX.example.com"));
+        assert!(!same_host("box.example.com", "box.other.net"));
+        assert!(!same_host("box", "server"));
+    }
+
+    #[test]
+    fn test_translate_to_monitor() {
+        let monitor = |x, w, h| Monitor::new("", false, x, 0, w, h);
+        let (left, right) = (monitor(0, 1920, 1080), monitor(1920, 3840, 2160));
+
+        // Relative position is scaled to the larger monitor and back
+        let geometry = Geometry::new(480, 270, 800, 600);
+        assert_eq!(translate_to_monitor(&geometry, &left, &right), (2880, 540));
+        assert_eq!(translate_to_monitor(&Geometry::new(2880, 540, 800, 600), &right, &left), (480, 270));
+
+        // Windows are kept on the target monitor and placed at its origin when too big to fit
+        assert_eq!(translate_to_monitor(&Geometry::new(5500, 0, 800, 600), &right, &left), (1120, 0));
+        assert_eq!(translate_to_monitor(&Geometry::new(100, -50, 800, 600), &left, &right), (2120, 0));
+        assert_eq!(translate_to_monitor(&Geometry::new(0, 0, 3000, 2000), &right, &left), (0, 0));
+    }
+
+    #[test]
+    fn test_translate_resize_percent() {
+        let size = Rect::new(800, 600);
+        let max = Rect::new(1910, 1007);
+        let hints = SizeHints::default();
+        assert_eq!(translate_resize_percent(&size, &hints, &max, 10.0, 0.0), (880, 600));
+        assert_eq!(translate_resize_percent(&size, &hints, &max, -50.0, -10.0), (400, 540));
+        assert_eq!(translate_resize_percent(&size, &hints, &max, 500.0, 500.0), (1910, 1007));
+        assert_eq!(translate_resize_percent(&size, &hints, &max, -100.0, -100.0), (1, 1));
+
+        // Size hints are applied after scaling
+        let hints = SizeHints {
+            flags: SIZE_HINTS_P_MIN_SIZE | SIZE_HINTS_P_RESIZE_INC,
+            min_width: 200,
+            min_height: 100,
+            width_inc: 7,
+            height_inc: 13,
+            ..Default::default()
+        };
+        assert_eq!(translate_resize_percent(&size, &hints, &max, 10.0, 10.0), (879, 659));
+        assert_eq!(translate_resize_percent(&size, &hints, &max, -90.0, -90.0), (200, 100));
+    }
+
+    #[test]
+    fn test_translate_opacity_toggle() {
+        assert_eq!(translate_opacity_toggle(1.0, 1.0, 0.6), 0.6);
+        assert_eq!(translate_opacity_toggle(0.6, 1.0, 0.6), 1.0);
+        assert_eq!(translate_opacity_toggle(0.599_999, 1.0, 0.6), 1.0);
+        assert_eq!(translate_opacity_toggle(0.3, 1.0, 0.6), 1.0);
+    }
+
+    #[test]
+    fn test_intersects() {
+        assert!(intersects((0, 0, 100, 100), (50, 50, 100, 100)));
+        assert!(intersects((0, 0, 100, 100), (10, 10, 10, 10)));
+        assert!(intersects((-50, -50, 100, 100), (0, 0, 10, 10)));
+
+        // Touching edges and disjoint rectangles don't overlap
+        assert!(!intersects((0, 0, 100, 100), (100, 0, 100, 100)));
+        assert!(!intersects((0, 0, 100, 100), (0, 100, 100, 100)));
+        assert!(!intersects((0, 0, 100, 100), (200, 200, 10, 10)));
+    }
+
+    #[test]
+    fn test_translate_grid() {
+        let area = Geometry::new(0, 0, 2560, 1415);
+
+        // No borders with adjacent cells sharing edges
+        let borders = Border::default();
+        let csd = Border::default();
+        let (x, y, w, h) = translate_grid(&borders, &csd, &area, 3, 2, 0, 0, 1, 1).unwrap();
+        assert_eq!((x, y, w, h), (0, 0, 853, 707));
+        let (x, y, w, h) = translate_grid(&borders, &csd, &area, 3, 2, 1, 1, 2, 1).unwrap();
+        assert_eq!((x, y, w, h), (853, 707, 1707, 708));
+
+        // With window manager borders
+        let borders = Border::new(5, 5, 10, 10);
+        let (x, y, w, h) = translate_grid(&borders, &csd, &area, 2, 1, 1, 0, 1, 1).unwrap();
+        assert_eq!((x, y, w, h), (1280, 0, 1280 - borders.w(), 1415 - borders.h()));
+
+        // With csd borders
+        let csd = Border::new(5, 5, 10, 10);
+        let (x, y, w, h) = translate_grid(&borders, &csd, &area, 2, 1, 1, 0, 1, 1).unwrap();
+        assert_eq!((x, y, w, h), (1280 - 5, -10, 1280 + csd.w(), 1415 + csd.h()));
+
+        // Cells out of range
+        assert!(translate_grid(&borders, &csd, &area, 2, 2, 1, 0, 2, 1).is_err());
+        assert!(translate_grid(&borders, &csd, &area, 2, 2, 0, 0, 0, 1).is_err());
+        assert!(translate_grid(&borders, &csd, &area, 0, 2, 0, 0, 1, 1).is_err());
+        assert!(translate_grid(&borders, &csd, &area, 3, 2, u32::MAX, 0, 1, 1).is_err());
+        assert!(translate_grid(&borders, &csd, &area, 3, 2, 0, 1, 1, u32::MAX).is_err());
+
+        // Cells are offset by the origin of the monitor's work area
+        let area = Geometry::new(1920, 30, 2560, 1410);
+        let (x, y, w, h) = translate_grid(&borders, &Border::default(), &area, 2, 2, 1, 1, 1, 1).unwrap();
+        assert_eq!((x, y, w, h), (1920 + 1280, 30 + 705, 1280 - borders.w(), 705 - borders.h()));
+    }
+
+    #[test]
+    fn test_translate_cascade() {
+        let area = Geometry::new(0, 0, 2000, 1000);
+        let geometries = translate_cascade(3, 30, 20, &area);
+        assert_eq!(geometries, vec![(0, 0, 1200, 600), (30, 20, 1200, 600), (60, 40, 1200, 600)]);
+
+        // Later windows are clamped to the work area
+        let geometries = translate_cascade(3, 500, 300, &area);
+        assert_eq!(geometries, vec![(0, 0, 1200, 600), (500, 300, 1200, 600), (800, 400, 1200, 600)]);
+
+        // Nothing to cascade
+        assert!(translate_cascade(0, 30, 20, &area).is_empty());
+
+        // Windows start from the origin of the monitor's work area e.g. right of a left dock
+        let area = Geometry::new(1968, 30, 2000, 1000);
+        let geometries = translate_cascade(3, 500, 300, &area);
+        assert_eq!(geometries, vec![(1968, 30, 1200, 600), (2468, 330, 1200, 600), (2768, 430, 1200, 600)]);
+    }
+
     #[test]
     fn test_translate_shape_halfw() {
         // No borders
@@ -601,7 +2702,7 @@ mod tests {
         let area = Rect { w: 2560, h: 1415 };
         let (g, _w, _h) = translate_shape(&size, &borders, &csd, &area, &Shape::Halfw).unwrap();
         let hw = (area.w as f32 / 2.0) as u32;
-        let fh = (area.h) as u32;
+        let fh = area.h;
         assert_eq!(g, None);
         assert_eq!(_w, Some(hw));
         assert_eq!(_h, Some(fh));
@@ -611,7 +2712,7 @@ mod tests {
         let area = Rect { w: 2560, h: 1415 };
         let (g, _w, _h) = translate_shape(&size, &borders, &csd, &area, &Shape::Halfw).unwrap();
         let hw = (area.w as f32 / 2.0) as u32 - borders.w();
-        let fh = (area.h) as u32 - borders.h();
+        let fh = area.h - borders.h();
         assert_eq!(g, None);
         assert_eq!(_w, Some(hw));
         assert_eq!(_h, Some(fh));
@@ -621,7 +2722,7 @@ mod tests {
         let area = Rect { w: 2560, h: 1415 };
         let (g, _w, _h) = translate_shape(&size, &borders, &csd, &area, &Shape::Halfw).unwrap();
         let hw = (area.w as f32 / 2.0) as u32 + csd.w();
-        let fh = (area.h) as u32 + csd.h();
+        let fh = area.h + csd.h();
         assert_eq!(g, None);
         assert_eq!(_w, Some(hw));
         assert_eq!(_h, Some(fh));
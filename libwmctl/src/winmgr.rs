@@ -23,16 +23,41 @@
 // ### Primitive Functions
 // * GetAtomName - get the name of an atom
 //
-use crate::{atoms::*, model::*, WmCtlError, WmCtlResult};
-use std::{collections::HashMap, str};
+use crate::{atoms::*, auth, model::*, WmCtlError, WmCtlResult};
+use std::{
+    collections::{HashMap, VecDeque},
+    os::fd::{AsRawFd, RawFd},
+    path::Path,
+    str,
+    sync::{mpsc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
 use tracing::debug;
 
 use x11rb::{
-    connection::Connection,
-    protocol::xproto::{ConnectionExt as _, *},
-    rust_connection::RustConnection,
+    connection::{Connection, RequestConnection},
+    errors::{ConnectError, DisplayParsingError},
+    protocol::{
+        randr::ConnectionExt as _,
+        xproto::{ConnectionExt as _, *},
+        Event as XEvent,
+    },
+    reexports::x11rb_protocol::parse_display::parse_display,
+    rust_connection::{DefaultStream, RustConnection},
+    wrapper::ConnectionExt as _,
 };
 
+// Maximum number of events held for later while waiting for others before the oldest are dropped
+const MAX_STASHED_EVENTS: usize = 1024;
+
+/// Outcome of checking for a change to a window without blocking
+pub(crate) enum WindowChange<T> {
+    Pending,    // nothing has changed yet
+    Changed(T), // the window changed
+    Destroyed,  // the window was destroyed
+}
+
 /// Window Manager provides a higher level interface to the underlying EWHM compatible window manager
 pub(crate) struct WinMgr {
     conn: RustConnection,            // x11 connection
@@ -44,8 +69,10 @@ pub(crate) struct WinMgr {
     root: u32,                       // root window id
     width: u32,                      // screen width
     height: u32,                     // screen height
-    desktops: u32,                   // number of desktops
+    pub(crate) desktops: u32,        // number of desktops
     compositing: bool,               // compositing manager running
+    screens: ScreenLayout,           // cache of the monitor layout
+    events: Mutex<VecDeque<XEvent>>, // events read while waiting for others
 
     // Crate properties
     pub(crate) work_width: u32,  // work area width (i.e. minus panels)
@@ -63,7 +90,93 @@ impl WinMgr {
     pub(crate) fn connect() -> WmCtlResult<Self> {
         debug!("connect: initializing connection...");
         let (conn, screen) = x11rb::connect(None)?;
+        Self::init(conn, screen)
+    }
+
+    /// Create a new connection to the given display rather than the one set by $DISPLAY
+    ///
+    /// ### Arguments
+    /// * `display` - X11 display to connect to e.g. `:99`
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect_to(":99").unwrap();
+    /// ```
+    pub(crate) fn connect_to(display: &str) -> WmCtlResult<Self> {
+        debug!("connect_to: initializing connection...");
+        let (conn, screen) = x11rb::connect(Some(display))?;
+        Self::init(conn, screen)
+    }
+
+    /// Create a new connection giving up if it isn't established within the given timeout e.g.
+    /// when the X socket is slow to accept over SSH forwarding. The connection is made on a
+    /// separate thread which is left to finish or fail on its own after a timeout.
+    ///
+    /// ### Arguments
+    /// * `display` - X11 display to connect to e.g. `:99` or None to use $DISPLAY
+    /// * `timeout` - how long to wait for the connection
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect_with_timeout(None, Duration::from_secs(5)).unwrap();
+    /// ```
+    pub(crate) fn connect_with_timeout(display: Option<&str>, timeout: Duration) -> WmCtlResult<Self> {
+        let name = display.map(|x| x.to_owned());
+        debug!("connect_with_timeout: display: {:?}, timeout: {:?}", name, timeout);
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let wm = match name {
+                Some(name) => Self::connect_to(&name),
+                None => Self::connect(),
+            };
+
+            // Receiver is gone if the connection timed out
+            let _ = tx.send(wm);
+        });
+        rx.recv_timeout(timeout).map_err(|_| WmCtlError::Timeout)?
+    }
+
+    /// Create a new connection to the given display authenticating with the given Xauthority file
+    /// rather than the one pointed to by $XAUTHORITY.
+    ///
+    /// ### Arguments
+    /// * `display` - X11 display to connect to e.g. `:0`
+    /// * `xauthority` - path to the Xauthority file to read credentials from
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect_with_auth(":0", Path::new("/home/user/.Xauthority")).unwrap();
+    /// ```
+    pub(crate) fn connect_with_auth(display: &str, xauthority: &Path) -> WmCtlResult<Self> {
+        let parsed = parse_display(Some(display)).map_err(ConnectError::from)?;
+        let screen = parsed.screen as usize;
+        debug!("connect_with_auth: display: {}, screen: {}, xauthority: {:?}", parsed.display, screen, xauthority);
+
+        // Try each of the connection options for the display in turn
+        let mut error = None;
+        for addr in parsed.connect_instruction() {
+            match DefaultStream::connect(&addr) {
+                Ok((stream, (family, address))) => {
+                    let (name, data) =
+                        auth::read_auth(xauthority, family, &address, parsed.display)?.unwrap_or_default();
+                    let conn = RustConnection::connect_to_stream_with_auth_info(stream, screen, name, data)?;
+                    return Self::init(conn, screen);
+                },
+                Err(err) => error = Some(err),
+            }
+        }
+        Err(match error {
+            Some(err) => ConnectError::IoError(err),
+            None => ConnectError::DisplayParsingError(DisplayParsingError::Unknown),
+        }
+        .into())
+    }
 
+    /// Initialize the window manager object from the given connection
+    fn init(conn: RustConnection, screen: usize) -> WmCtlResult<Self> {
         // Get the screen size
         let (width, height, root) = {
             let screen = &conn.setup().roots[screen];
@@ -88,6 +201,8 @@ impl WinMgr {
             work_height: Default::default(),
             desktops: Default::default(),
             compositing: Default::default(),
+            screens: Default::default(),
+            events: Default::default(),
         };
 
         // Fill in missing properties that require a connection and supported atoms init_caching
@@ -100,11 +215,331 @@ impl WinMgr {
         wm.desktops = wm.desktops()?;
         wm.compositing = wm.compositing()?;
         wm.supported = wm.supported()?;
+        wm.screens = wm.screens()?;
 
         debug!("connect: screen: {}, root: {}, w: {}, h: {}", screen, root, width, height);
         Ok(wm)
     }
 
+    /// Get the file descriptor of the underlying X11 connection. This allows for waiting on the
+    /// connection with `select` or `epoll` before draining events with `poll_event`.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let fd = wm.connection_fd();
+    /// ```
+    pub(crate) fn connection_fd(&self) -> RawFd {
+        self.conn.stream().as_raw_fd()
+    }
+
+    /// Select structure and property change events on the root window such that window
+    /// management events are reported by `poll_event`
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.select_root_events().unwrap();
+    /// ```
+    pub(crate) fn select_root_events(&self) -> WmCtlResult<()> {
+        let mask = EventMask::SUBSTRUCTURE_NOTIFY | EventMask::PROPERTY_CHANGE;
+        self.conn
+            .change_window_attributes(self.root, &ChangeWindowAttributesAux::new().event_mask(mask))?
+            .check()?;
+        debug!("select_root_events: root: {}", self.root);
+        Ok(())
+    }
+
+    /// Get the next queued event relevant to window management without blocking. Queued events
+    /// that aren't relevant are discarded. Events held back while waiting for others are
+    /// reported first.
+    ///
+    /// ### Returns
+    /// * `Some(event)` if a relevant event was queued or `None` if the queue is empty
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// while let Some(event) = wm.poll_event().unwrap() {
+    ///     println!("{:?}", event);
+    /// }
+    /// ```
+    pub(crate) fn poll_event(&self) -> WmCtlResult<Option<Event>> {
+        loop {
+            let stashed = self.events.lock().unwrap().pop_front();
+            let event = match stashed {
+                Some(event) => event,
+                None => match self.conn.poll_for_event()? {
+                    Some(event) => event,
+                    None => return Ok(None),
+                },
+            };
+            if let Some(event) = Event::from(event) {
+                debug!("poll_event: {:?}", event);
+                return Ok(Some(event));
+            }
+        }
+    }
+
+    /// Take the first event that matches without blocking, checking the events held back by
+    /// earlier waits before those queued on the connection. Queued events that don't match are
+    /// held back for later waits and `poll_event` rather than being discarded.
+    ///
+    /// ### Arguments
+    /// * `matches` - predicate selecting the event to take
+    fn take_event(&self, matches: impl Fn(&XEvent) -> bool) -> WmCtlResult<Option<XEvent>> {
+        let mut stash = self.events.lock().unwrap();
+        if let Some(i) = stash.iter().position(&matches) {
+            return Ok(stash.remove(i));
+        }
+        while let Some(event) = self.conn.poll_for_event()? {
+            if matches(&event) {
+                return Ok(Some(event));
+            }
+            if stash.len() >= MAX_STASHED_EVENTS {
+                debug!("take_event: dropped: {:?}", stash.pop_front());
+            }
+            stash.push_back(event);
+        }
+        Ok(None)
+    }
+
+    /// Block until an event that matches is taken as per `take_event` or the timeout elapses
+    ///
+    /// ### Arguments
+    /// * `matches` - predicate selecting the event to take
+    /// * `timeout` - maximum time to wait
+    ///
+    /// ### Returns
+    /// * the event or `None` if the timeout elapsed
+    fn wait_event(&self, matches: impl Fn(&XEvent) -> bool, timeout: Duration) -> WmCtlResult<Option<XEvent>> {
+        let start = Instant::now();
+        loop {
+            if let Some(event) = self.take_event(&matches)? {
+                return Ok(Some(event));
+            }
+            if start.elapsed() >= timeout {
+                return Ok(None);
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    /// Select property change and structure events on the given window such that changes to the
+    /// window can be watched for
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.select_window_events(1234).unwrap();
+    /// ```
+    pub(crate) fn select_window_events(&self, id: u32) -> WmCtlResult<()> {
+        let mask = EventMask::PROPERTY_CHANGE | EventMask::STRUCTURE_NOTIFY;
+        self.conn.change_window_attributes(id, &ChangeWindowAttributesAux::new().event_mask(mask))?.check()?;
+        debug!("select_window_events: id: {}", id);
+        Ok(())
+    }
+
+    /// Check for a change to the given property on the given window without blocking. Any further
+    /// changes already queued are coalesced into the one change. Events must first be selected on
+    /// the window with `select_window_events`.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to watch
+    /// * `atom` - property to watch for changes
+    fn poll_window_property(&self, id: u32, atom: u32) -> WmCtlResult<WindowChange<()>> {
+        let matches = |event: &XEvent| match event {
+            XEvent::PropertyNotify(e) => e.window == id && e.atom == atom,
+            XEvent::DestroyNotify(e) => e.window == id,
+            _ => false,
+        };
+        let mut change = WindowChange::Pending;
+        while let Some(event) = self.take_event(matches)? {
+            if let XEvent::DestroyNotify(_) = event {
+                return Ok(WindowChange::Destroyed);
+            }
+            change = WindowChange::Changed(());
+        }
+        Ok(change)
+    }
+
+    /// Check for the window being configured i.e. moved, resized or restacked without blocking.
+    /// Events must first be selected on the window with `select_window_events`.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to watch
+    ///
+    /// ### Returns
+    /// * the window's new geometry in root coordinates once configured
+    pub(crate) fn poll_window_geometry(&self, id: u32) -> WmCtlResult<WindowChange<Geometry>> {
+        let matches = |event: &XEvent| match event {
+            XEvent::ConfigureNotify(e) => e.window == id,
+            XEvent::DestroyNotify(e) => e.window == id,
+            _ => false,
+        };
+        match self.take_event(matches)? {
+            Some(XEvent::ConfigureNotify(e)) => {
+                // Synthetic notifications sent by the window manager as per ICCCM are already
+                // in root coordinates while real ones are relative to the parent e.g. the frame
+                let (x, y) = if e.response_type & 0x80 != 0 {
+                    (e.x as i32, e.y as i32)
+                } else {
+                    let parent = self.conn.query_tree(id)?.reply()?.parent;
+                    let tx = self.conn.translate_coordinates(parent, self.root, e.x, e.y)?.reply()?;
+                    (tx.dst_x as i32, tx.dst_y as i32)
+                };
+                let geometry = Geometry::new(x, y, e.width as u32, e.height as u32);
+                debug!("poll_window_geometry: id: {}, {:?}", id, geometry);
+                Ok(WindowChange::Changed(geometry))
+            },
+            Some(_) => Ok(WindowChange::Destroyed),
+            None => Ok(WindowChange::Pending),
+        }
+    }
+
+    /// Check for the window being configured without blocking, draining all of the configure
+    /// notifications already queued. Events must first be selected on the window with
+    /// `select_window_events`.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to watch
+    pub(crate) fn poll_window_configured(&self, id: u32) -> WmCtlResult<WindowChange<()>> {
+        let matches = |event: &XEvent| match event {
+            XEvent::ConfigureNotify(e) => e.window == id,
+            XEvent::DestroyNotify(e) => e.window == id,
+            _ => false,
+        };
+        let mut change = WindowChange::Pending;
+        while let Some(event) = self.take_event(matches)? {
+            if let XEvent::DestroyNotify(_) = event {
+                return Ok(WindowChange::Destroyed);
+            }
+            change = WindowChange::Changed(());
+        }
+        Ok(change)
+    }
+
+    /// Check for the window being mapped or unmapped without blocking. Events must first be
+    /// selected on the window with `select_window_events`.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to watch
+    ///
+    /// ### Returns
+    /// * `true` if the window was mapped or `false` if unmapped
+    pub(crate) fn poll_window_map_state(&self, id: u32) -> WmCtlResult<WindowChange<bool>> {
+        let matches = |event: &XEvent| match event {
+            XEvent::MapNotify(e) => e.window == id,
+            XEvent::UnmapNotify(e) => e.window == id,
+            XEvent::DestroyNotify(e) => e.window == id,
+            _ => false,
+        };
+        match self.take_event(matches)? {
+            Some(XEvent::MapNotify(_)) => Ok(WindowChange::Changed(true)),
+            Some(XEvent::UnmapNotify(_)) => Ok(WindowChange::Changed(false)),
+            Some(_) => Ok(WindowChange::Destroyed),
+            None => Ok(WindowChange::Pending),
+        }
+    }
+
+    /// Check for the window's opacity changing without blocking. Events must first be selected on
+    /// the window with `select_window_events`.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to watch
+    ///
+    /// ### Returns
+    /// * the window's new opacity once changed
+    pub(crate) fn poll_window_opacity(&self, id: u32) -> WmCtlResult<WindowChange<f64>> {
+        Ok(match self.poll_window_property(id, self.atoms._NET_WM_WINDOW_OPACITY)? {
+            WindowChange::Changed(()) => WindowChange::Changed(self.window_opacity(id)?),
+            WindowChange::Pending => WindowChange::Pending,
+            WindowChange::Destroyed => WindowChange::Destroyed,
+        })
+    }
+
+    /// Check for the window's desktop changing without blocking. Events must first be selected on
+    /// the window with `select_window_events`.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to watch
+    ///
+    /// ### Returns
+    /// * the window's new desktop or -1 if sticky once changed
+    pub(crate) fn poll_window_desktop(&self, id: u32) -> WmCtlResult<WindowChange<i32>> {
+        Ok(match self.poll_window_property(id, self.atoms._NET_WM_DESKTOP)? {
+            WindowChange::Changed(()) => WindowChange::Changed(self.window_desktop(id)?),
+            WindowChange::Pending => WindowChange::Pending,
+            WindowChange::Destroyed => WindowChange::Destroyed,
+        })
+    }
+
+    /// Block until the X server has processed all requests sent so far by doing a round trip. Any
+    /// errors of requests that weren't checked are reported as events rather than returned.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.sync().unwrap();
+    /// ```
+    pub(crate) fn sync(&self) -> WmCtlResult<()> {
+        self.conn.sync()?;
+        debug!("sync");
+        Ok(())
+    }
+
+    /// Get the current X server time to use as the timestamp of requests. Passing CURRENT_TIME
+    /// instead causes some window managers to ignore requests as part of focus stealing prevention.
+    /// The time is obtained by appending nothing to a property of a temporary window and reading
+    /// the time off of the resulting PropertyNotify. Other events queued while waiting are held
+    /// back for `poll_event` and the window watchers. Fails with `Timeout` if the notification
+    /// doesn't arrive within a second.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let time = wm.server_time().unwrap();
+    /// ```
+    pub(crate) fn server_time(&self) -> WmCtlResult<u32> {
+        // Using a temporary window rather than the root avoids changing the root's event mask
+        let win = self.conn.generate_id()?;
+        self.conn.create_window(
+            x11rb::COPY_DEPTH_FROM_PARENT,
+            win,
+            self.root,
+            -1,
+            -1,
+            1,
+            1,
+            0,
+            WindowClass::INPUT_ONLY,
+            x11rb::COPY_FROM_PARENT,
+            &CreateWindowAux::new().event_mask(EventMask::PROPERTY_CHANGE),
+        )?;
+        self.conn.change_property8(PropMode::APPEND, win, AtomEnum::WM_NAME, AtomEnum::STRING, &[])?;
+        self.conn.flush()?;
+
+        let matches = |event: &XEvent| matches!(event, XEvent::PropertyNotify(e) if e.window == win);
+        let event = self.wait_event(matches, Duration::from_secs(1));
+        self.conn.destroy_window(win)?;
+        match event? {
+            Some(XEvent::PropertyNotify(e)) => {
+                debug!("server_time: {}", e.time);
+                Ok(e.time)
+            },
+            _ => Err(WmCtlError::Timeout.into()),
+        }
+    }
+
     /// Convert the given Atom id into an Atom name
     ///
     /// ### Examples
@@ -120,7 +555,7 @@ impl WinMgr {
             debug!("atom_name: id: {}, name: {}", id, value.to_owned());
             return Ok(value.to_owned());
         }
-        return Ok("".to_string());
+        Ok("".to_string())
     }
 
     /// Convert the given Atom ids into Atom map of id => name. By doing this in bulk
@@ -134,20 +569,33 @@ impl WinMgr {
     /// ```
     #[allow(dead_code)]
     pub(crate) fn atom_map(&self, ids: &[u32]) -> WmCtlResult<HashMap<u32, String>> {
-        let mut atoms = HashMap::<u32, String>::new();
+        let names = self.atom_names(ids)?;
+        Ok(ids.iter().copied().zip(names).filter(|(_, name)| !name.is_empty()).collect())
+    }
 
+    /// Convert the given Atom ids into Atom names in the same order. All requests are sent
+    /// before any replies are waited on making this a single round trip rather than one per atom.
+    /// * Names that aren't valid UTF-8 are returned as empty strings
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.atom_names(&[1, 2, 3]).unwrap()
+    /// ```
+    pub(crate) fn atom_names(&self, ids: &[u32]) -> WmCtlResult<Vec<String>> {
         // Faster and more efficient to send all requests before calling reply()
         let cookies = ids.iter().map(|id| self.conn.get_atom_name(*id)).collect::<Vec<_>>();
 
         // Now take the cookies and ids and process the replies
+        let mut names = Vec::with_capacity(ids.len());
         for (cookie, id) in cookies.into_iter().zip(ids.iter()) {
             let reply = cookie?.reply()?;
-            if let Ok(name) = str::from_utf8(&reply.name) {
-                atoms.insert(*id, name.to_owned());
-                debug!("atom_names: id: {}, name: {}", id, name);
-            }
+            let name = str::from_utf8(&reply.name).unwrap_or_default().to_owned();
+            debug!("atom_names: id: {}, name: {}", id, name);
+            names.push(name);
         }
-        return Ok(atoms);
+        Ok(names)
     }
 
     /// Get window manager's informational properties
@@ -159,6 +607,7 @@ impl WinMgr {
     /// wm.info().unwrap()
     /// ```
     pub(crate) fn info(&self) -> WmCtlResult<Info> {
+        let (num_managed_windows, num_all_windows) = self.window_counts()?;
         Ok(Info {
             id: self.id,
             name: self.name.clone(),
@@ -166,11 +615,60 @@ impl WinMgr {
             work_area: (self.work_width, self.work_height),
             screen_size: (self.width, self.height),
             desktops: self.desktops,
+            active_desktop: self.active_desktop().unwrap_or_default(),
+            num_managed_windows,
+            num_all_windows,
             compositing: self.compositing,
             supported: self.supported.clone(),
         })
     }
 
+    /// Refresh the volatile fields of the given window manager's informational properties by
+    /// reading them from the server rather than the values cached when the connection was made
+    ///
+    /// ### Arguments
+    /// * `info` - informational properties to refresh
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let mut info = wm.info().unwrap();
+    /// wm.refresh_info(&mut info).unwrap();
+    /// ```
+    pub(crate) fn refresh_info(&self, info: &mut Info) -> WmCtlResult<()> {
+        info.work_area = self.workarea()?;
+        info.desktops = self.desktops()?;
+        info.active_desktop = self.active_desktop().unwrap_or_default();
+        info.compositing = self.compositing()?;
+        (info.num_managed_windows, info.num_all_windows) = self.window_counts()?;
+        debug!("refresh_info: desktops: {}, active: {}", info.desktops, info.active_desktop);
+        Ok(())
+    }
+
+    /// Count the managed windows and all X11 windows. Rather than enumerating the windows twice
+    /// both requests are sent before either reply is waited on and only the lengths are read.
+    ///
+    /// ### Returns
+    /// * `(managed, all)` number of windows
+    fn window_counts(&self) -> WmCtlResult<(usize, usize)> {
+        // Defined as: _NET_CLIENT_LIST, WINDOW[]/32
+        // with only the length of the value being of interest
+        let clients = self.conn.get_property(
+            false,
+            self.root,
+            self.atoms._NET_CLIENT_LIST,
+            AtomEnum::WINDOW,
+            0,
+            u32::MAX,
+        )?;
+        let tree = self.conn.query_tree(self.root)?;
+        let managed = clients.reply()?.value_len as usize;
+        let all = tree.reply()?.children.len();
+        debug!("window_counts: managed: {}, all: {}", managed, all);
+        Ok((managed, all))
+    }
+
     /// Get the active window id
     ///
     /// ### Examples
@@ -216,6 +714,183 @@ impl WinMgr {
         Ok(id + 1)
     }
 
+    /// Get a summary of the desktops for status bars. All root properties are requested before
+    /// any replies are waited on and likewise for the desktop of each window such that the whole
+    /// summary takes only two round trips.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let summary = wm.workspace_summary().unwrap();
+    /// ```
+    pub(crate) fn workspace_summary(&self) -> WmCtlResult<WorkspaceSummary> {
+        let get = |atom, kind: AtomEnum| self.conn.get_property(false, self.root, atom, kind, 0, u32::MAX);
+        let desktops = get(self.atoms._NET_NUMBER_OF_DESKTOPS, AtomEnum::CARDINAL)?;
+        let active = get(self.atoms._NET_CURRENT_DESKTOP, AtomEnum::CARDINAL)?;
+        let showing = get(self.atoms._NET_SHOWING_DESKTOP, AtomEnum::CARDINAL)?;
+        let clients = get(self.atoms._NET_CLIENT_LIST, AtomEnum::WINDOW)?;
+        let names = self.conn.get_property(
+            false,
+            self.root,
+            self.atoms._NET_DESKTOP_NAMES,
+            self.atoms.UTF8_STRING,
+            0,
+            u32::MAX,
+        )?;
+
+        let desktops = desktops
+            .reply()?
+            .value32()
+            .and_then(|mut x| x.next())
+            .ok_or(WmCtlError::PropertyNotFound("_NET_NUMBER_OF_DESKTOPS".to_owned()))?;
+        let active = active
+            .reply()?
+            .value32()
+            .and_then(|mut x| x.next())
+            .ok_or(WmCtlError::PropertyNotFound("_NET_CURRENT_DESKTOP".to_owned()))?;
+        let showing = showing.reply()?.value32().and_then(|mut x| x.next()).is_some_and(|x| x == 1);
+        let clients = clients
+            .reply()?
+            .value32()
+            .ok_or(WmCtlError::PropertyNotFound("_NET_CLIENT_LIST".to_owned()))?
+            .collect::<Vec<_>>();
+        let names = pad_desktop_names(parse_strings(&names.reply()?.value)?, desktops);
+
+        // Faster and more efficient to send all requests before calling reply()
+        let mut cookies = Vec::with_capacity(clients.len());
+        for &id in clients.iter() {
+            cookies.push((
+                id,
+                self.conn.get_property(false, id, self.atoms._NET_WM_DESKTOP, AtomEnum::CARDINAL, 0, 1)?,
+            ));
+        }
+
+        // Windows destroyed while the summary is gathered are skipped
+        let mut values = Vec::with_capacity(cookies.len());
+        for (id, cookie) in cookies {
+            match cookie.reply() {
+                Ok(reply) => values.extend(reply.value32().and_then(|mut x| x.next())),
+                Err(e) => debug!("workspace_summary: id: {}, skipped: {}", id, e),
+            }
+        }
+
+        let summary = WorkspaceSummary {
+            desktops,
+            names,
+            active: active + 1,
+            windows: WorkspaceSummary::count_windows(desktops, &values),
+            showing_desktop: showing,
+        };
+        debug!("workspace_summary: {:?}", summary);
+        Ok(summary)
+    }
+
+    /// Get the names of the desktops in desktop order. Window managers may publish fewer names than
+    /// there are desktops in which case the missing names default to `Desktop N`, names beyond the
+    /// number of desktops are dropped.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let names = wm.desktop_names().unwrap();
+    /// ```
+    pub(crate) fn desktop_names(&self) -> WmCtlResult<Vec<String>> {
+        // Defined as: _NET_DESKTOP_NAMES, UTF8_STRING[]
+        // which is a list of null terminated strings
+        let reply = self
+            .conn
+            .get_property(false, self.root, self.atoms._NET_DESKTOP_NAMES, self.atoms.UTF8_STRING, 0, u32::MAX)?
+            .reply()?;
+        let names = pad_desktop_names(parse_strings(&reply.value)?, self.desktops()?);
+        debug!("desktop_names: {:?}", names);
+        Ok(names)
+    }
+
+    /// Get the details of all managed windows for a listing. All requests for all windows are sent
+    /// before any replies are waited on such that the whole listing takes a single round trip.
+    /// Windows destroyed while the listing is gathered are skipped.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let rows = wm.window_rows().unwrap();
+    /// ```
+    pub(crate) fn window_rows(&self) -> WmCtlResult<Vec<WindowRow>> {
+        let get = |id, atom: u32, kind: u32| self.conn.get_property(false, id, atom, kind, 0, u32::MAX);
+        let (cardinal, string, utf8) =
+            (AtomEnum::CARDINAL.into(), AtomEnum::STRING.into(), self.atoms.UTF8_STRING);
+
+        // Faster and more efficient to send all requests before calling reply()
+        let mut cookies = vec![];
+        for id in self.windows(false)? {
+            cookies.push((
+                id,
+                get(id, self.atoms._NET_WM_DESKTOP, cardinal)?,
+                get(id, self.atoms._NET_WM_PID, cardinal)?,
+                get(id, AtomEnum::WM_CLIENT_MACHINE.into(), string)?,
+                get(id, AtomEnum::WM_CLASS.into(), string)?,
+                [
+                    get(id, self.atoms._NET_WM_VISIBLE_NAME, utf8)?,
+                    get(id, self.atoms._NET_WM_NAME, utf8)?,
+                    get(id, AtomEnum::WM_NAME.into(), string)?,
+                ],
+                get(id, self.atoms._NET_FRAME_EXTENTS, cardinal)?,
+                get(id, self.atoms._GTK_FRAME_EXTENTS, cardinal)?,
+                self.conn.get_geometry(id)?,
+                self.conn.translate_coordinates(id, self.root, 0, 0)?,
+            ));
+        }
+
+        // Now take the cookies and process the replies
+        let mut rows = Vec::with_capacity(cookies.len());
+        for (id, desktop, pid, host, class, names, frame, gtk, geometry, translate) in cookies {
+            let row = || -> WmCtlResult<WindowRow> {
+                let first = |reply: GetPropertyReply| reply.value32().and_then(|mut x| x.next());
+                let border = |reply: GetPropertyReply| {
+                    let v = reply.value32().map(|x| x.collect::<Vec<_>>()).unwrap_or_default();
+                    match v[..] {
+                        [l, r, t, b] => Border::new(l, r, t, b),
+                        _ => Border::default(),
+                    }
+                };
+                let text = |value: &[u8]| String::from_utf8_lossy(value).trim_end_matches('\0').to_owned();
+
+                let desktop = match first(desktop.reply()?) {
+                    Some(DESKTOP_STICKY) | None => -1,
+                    Some(x) => x as i32 + 1,
+                };
+                let class = class.reply()?.value;
+                let class = class.split(|x| *x == 0).nth(1).map(text).unwrap_or_default();
+                let mut title = String::new();
+                for name in names {
+                    let name = text(&name.reply()?.value);
+                    if title.is_empty() && !name.is_empty() {
+                        title = name;
+                    }
+                }
+                let (g, tx) = (geometry.reply()?, translate.reply()?);
+                let raw = (tx.dst_x as i32, tx.dst_y as i32, g.width as u32, g.height as u32);
+                Ok(WindowRow {
+                    id,
+                    desktop,
+                    pid: first(pid.reply()?).unwrap_or_default() as i32,
+                    host: text(&host.reply()?.value),
+                    class,
+                    title,
+                    geometry: Geometry::from(visual_geometry(raw, &border(frame.reply()?), &border(gtk.reply()?))),
+                })
+            };
+            match row() {
+                Ok(row) => rows.push(row),
+                Err(e) => debug!("window_rows: id: {}, skipped: {}", id, e),
+            }
+        }
+        Ok(rows)
+    }
+
     /// Get the Window Manager's supported functions.
     ///
     /// ### Examples
@@ -248,7 +923,26 @@ impl WinMgr {
     /// ```
     #[allow(dead_code)]
     pub(crate) fn is_supported(&self, atom: u32) -> bool {
-        self.supported.get(&atom).is_some()
+        self.supported.contains_key(&atom)
+    }
+
+    /// Determine if all the given functions are supported by the window manager e.g. to check up
+    /// front that a multi step operation can be fully applied. This will check the cached set of
+    /// Window Manager's supported functions for a match by name.
+    ///
+    /// ### Arguments
+    /// * `names` - atom names to lookup to see if they are all supported
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.supports_all(&["_NET_WM_STATE", "_NET_WM_DESKTOP", "_NET_MOVERESIZE_WINDOW"]);
+    /// ```
+    pub(crate) fn supports_all(&self, names: &[&str]) -> bool {
+        let result = names.iter().all(|name| self.supported.values().any(|x| x == name));
+        debug!("supports_all: names: {:?}, result: {}", names, result);
+        result
     }
 
     /// Get window manager's window id and name
@@ -268,7 +962,7 @@ impl WinMgr {
             .value32()
             .and_then(|mut x| x.next())
             .ok_or(WmCtlError::PropertyNotFound("_NET_SUPPORTING_WM_CHECK".to_owned()))?;
-        let name = self.window_name(id)?;
+        let name = self.window_name(id, false)?;
         debug!("winmgr: id: {}, name: {}", id, name);
         Ok((id, name))
     }
@@ -308,35 +1002,176 @@ impl WinMgr {
         Ok((w, h))
     }
 
-    /// Check if a composit manager is running
+    /// Get the cached monitor layout of the screen. The cache is populated on connect and only
+    /// updated by an explicit call to `refresh_screens` e.g. after a monitor hotplug. The work area
+    /// of each monitor is computed on each call from the current dock struts as panels come and go.
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let wm = WinMgr::connect().unwrap();
-    /// wm.compositing().unwrap();
+    /// let layout = wm.screen_layout().unwrap();
     /// ```
-    fn compositing(&self) -> WmCtlResult<bool> {
-        // Defined as: _NET_WM_CM_Sn
-        // For each screen the compositing manager manages they MUST acquire ownership of a
-        // selection named _NET_WM_CM_Sn, where the suffix `n` is the screen number.
-        let atom = format!("_NET_WM_CM_S{}", self.screen);
-        let atom = self.conn.intern_atom(false, atom.as_bytes())?.reply()?.atom;
-        let reply = self.conn.get_selection_owner(atom)?.reply()?;
-        let result = reply.owner != x11rb::NONE;
-        debug!("composite_manager: {}", result);
-        Ok(result)
+    pub(crate) fn screen_layout(&self) -> WmCtlResult<ScreenLayout> {
+        let mut layout = self.screens.clone();
+        layout.monitors = self.reserve_struts(layout.monitors)?;
+        Ok(layout)
     }
 
-    /// Get number of desktops
+    /// Query the monitor layout again and update the cache
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
-    /// let wm = WinMgr::connect().unwrap();
-    /// wm.desktops().unwrap();
+    /// let mut wm = WinMgr::connect().unwrap();
+    /// wm.refresh_screens().unwrap();
     /// ```
-    fn desktops(&self) -> WmCtlResult<u32> {
+    pub(crate) fn refresh_screens(&mut self) -> WmCtlResult<()> {
+        self.screens = self.screens()?;
+        Ok(())
+    }
+
+    /// Get the monitor layout from RandR falling back on a single monitor the size of the screen
+    /// when the server doesn't support RandR 1.5 monitors.
+    /// * RandR 1.5 monitors are used rather than enumerating the CRTCs of the screen resources as
+    ///   they are what window managers lay windows out on. The server creates one for each active
+    ///   output named after it e.g. `DP-1` while user defined monitors e.g. `xrandr --setmonitor`
+    ///   have arbitrary names and may span several outputs or split a single one.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.screens().unwrap();
+    /// ```
+    fn screens(&self) -> WmCtlResult<ScreenLayout> {
+        let fallback = || Monitor::new("default", true, 0, 0, self.width, self.height);
+        if self.conn.extension_information(x11rb::protocol::randr::X11_EXTENSION_NAME)?.is_none() {
+            debug!("screens: randr not supported");
+            return Ok(ScreenLayout::new(vec![fallback()]));
+        }
+
+        // Only active monitors are of interest
+        let reply = match self.conn.randr_get_monitors(self.root, true)?.reply() {
+            Ok(reply) => reply,
+            Err(e) => {
+                debug!("screens: randr monitors not supported: {}", e);
+                return Ok(ScreenLayout::new(vec![fallback()]));
+            },
+        };
+        let names = self.atom_names(&reply.monitors.iter().map(|x| x.name).collect::<Vec<_>>())?;
+        let mut monitors = reply
+            .monitors
+            .iter()
+            .zip(names)
+            .map(|(x, name)| {
+                Monitor::new(&name, x.primary, x.x as i32, x.y as i32, x.width as u32, x.height as u32)
+            })
+            .collect::<Vec<_>>();
+        if monitors.is_empty() {
+            return Ok(ScreenLayout::new(vec![fallback()]));
+        }
+
+        // Name the outputs driving each monitor sending all the requests before waiting on any
+        let timestamp = self.conn.randr_get_screen_resources_current(self.root)?.reply()?.config_timestamp;
+        let cookies = reply
+            .monitors
+            .iter()
+            .map(|x| x.outputs.iter().map(|&output| self.conn.randr_get_output_info(output, timestamp)).collect())
+            .collect::<Result<Vec<Vec<_>>, _>>()?;
+        for (monitor, cookies) in monitors.iter_mut().zip(cookies) {
+            for cookie in cookies {
+                match cookie.reply() {
+                    Ok(info) => monitor.outputs.push(String::from_utf8_lossy(&info.name).into_owned()),
+                    Err(e) => debug!("screens: monitor: {}, output skipped: {}", monitor.name, e),
+                }
+            }
+        }
+        for monitor in monitors.iter() {
+            debug!("screens: {:?}", monitor);
+        }
+        Ok(ScreenLayout::new(monitors))
+    }
+
+    /// Get the monitors of the screen layout along with the work area of each monitor i.e. the
+    /// area not reserved by dock struts. The monitors, their names and their order are the same as
+    /// `screen_layout().monitors` such that indices and names can be used interchangeably.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// for monitor in wm.monitors().unwrap() {
+    ///     println!("{}: {:?}", monitor.name, monitor.work_area);
+    /// }
+    /// ```
+    pub(crate) fn monitors(&self) -> WmCtlResult<Vec<Monitor>> {
+        Ok(self.screen_layout()?.monitors)
+    }
+
+    /// Shrink the work area of the given monitors by the struts of all client windows. All strut
+    /// requests are sent before any replies are waited on and windows that vanish while being
+    /// inspected are skipped.
+    fn reserve_struts(&self, mut monitors: Vec<Monitor>) -> WmCtlResult<Vec<Monitor>> {
+        // Faster and more efficient to send all requests before calling reply()
+        let mut cookies = vec![];
+        for id in self.windows(false).unwrap_or_default() {
+            cookies.push((
+                id,
+                self.conn.get_property(false, id, self.atoms._NET_WM_STRUT_PARTIAL, AtomEnum::CARDINAL, 0, 12)?,
+                self.conn.get_property(false, id, self.atoms._NET_WM_STRUT, AtomEnum::CARDINAL, 0, 4)?,
+            ));
+        }
+
+        let mut struts = vec![];
+        for (id, partial, full) in cookies {
+            let strut = || -> WmCtlResult<Option<Strut>> {
+                let values = |reply: GetPropertyReply| reply.value32().map(|x| x.collect::<Vec<_>>());
+                let (partial, full) = (values(partial.reply()?), values(full.reply()?));
+                self.parse_struts(&partial.unwrap_or_default(), &full.unwrap_or_default())
+            };
+            match strut() {
+                Ok(strut) => struts.extend(strut),
+                Err(e) => debug!("reserve_struts: id: {}, skipped: {}", id, e),
+            }
+        }
+        for monitor in monitors.iter_mut() {
+            for strut in struts.iter() {
+                monitor.reserve(strut, self.width, self.height);
+            }
+        }
+        Ok(monitors)
+    }
+
+    /// Check if a composit manager is running
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.compositing().unwrap();
+    /// ```
+    fn compositing(&self) -> WmCtlResult<bool> {
+        // Defined as: _NET_WM_CM_Sn
+        // For each screen the compositing manager manages they MUST acquire ownership of a
+        // selection named _NET_WM_CM_Sn, where the suffix `n` is the screen number.
+        let atom = format!("_NET_WM_CM_S{}", self.screen);
+        let atom = self.conn.intern_atom(false, atom.as_bytes())?.reply()?.atom;
+        let reply = self.conn.get_selection_owner(atom)?.reply()?;
+        let result = reply.owner != x11rb::NONE;
+        debug!("composite_manager: {}", result);
+        Ok(result)
+    }
+
+    /// Get number of desktops
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.desktops().unwrap();
+    /// ```
+    fn desktops(&self) -> WmCtlResult<u32> {
         // Defined as: _NET_NUMBER_OF_DESKTOPS, CARDINAL/32
         // which means when retrieving the value via `get_property` that we need to use a `self.atoms._NET_NUMBER_OF_DESKTOPS`
         // request message with a `AtomEnum::CARDINAL` type response and we can use the `reply.value32()` accessor to
@@ -352,6 +1187,99 @@ impl WinMgr {
         debug!("desktops: {}", num);
         Ok(num)
     }
+
+    /// Get the desktop geometry i.e. the size of the large virtual desktop shared by all desktops.
+    /// Window managers that don't support large desktops will report the screen size.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let (w, h) = wm.desktop_geometry().unwrap();
+    /// ```
+    pub(crate) fn desktop_geometry(&self) -> WmCtlResult<(u32, u32)> {
+        // Defined as: _NET_DESKTOP_GEOMETRY width, height, CARDINAL[2]/32
+        let reply = self
+            .conn
+            .get_property(false, self.root, self.atoms._NET_DESKTOP_GEOMETRY, AtomEnum::CARDINAL, 0, u32::MAX)?
+            .reply()?;
+        let mut values =
+            reply.value32().ok_or(WmCtlError::PropertyNotFound("_NET_DESKTOP_GEOMETRY".to_owned()))?;
+        let w = values.next().ok_or(WmCtlError::PropertyNotFound("_NET_DESKTOP_GEOMETRY width".to_owned()))?;
+        let h = values.next().ok_or(WmCtlError::PropertyNotFound("_NET_DESKTOP_GEOMETRY height".to_owned()))?;
+        debug!("desktop_geometry: w: {}, h: {}", w, h);
+        Ok((w, h))
+    }
+
+    /// Get the top left corner of the current desktop's viewport within the large desktop.
+    /// Window managers that don't support large desktops will report (0, 0).
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let (x, y) = wm.desktop_viewport().unwrap();
+    /// ```
+    pub(crate) fn desktop_viewport(&self) -> WmCtlResult<(u32, u32)> {
+        // Defined as: _NET_DESKTOP_VIEWPORT x, y, CARDINAL[][2]/32
+        // with a pair of values for each desktop so we pick out the pair for the current desktop.
+        let reply = self
+            .conn
+            .get_property(false, self.root, self.atoms._NET_DESKTOP_VIEWPORT, AtomEnum::CARDINAL, 0, u32::MAX)?
+            .reply()?;
+        let current = self.active_desktop().map_or(0, |x| x - 1) as usize;
+        let mut values = reply
+            .value32()
+            .ok_or(WmCtlError::PropertyNotFound("_NET_DESKTOP_VIEWPORT".to_owned()))?
+            .skip(current * 2);
+        let x = values.next().ok_or(WmCtlError::PropertyNotFound("_NET_DESKTOP_VIEWPORT x".to_owned()))?;
+        let y = values.next().ok_or(WmCtlError::PropertyNotFound("_NET_DESKTOP_VIEWPORT y".to_owned()))?;
+        debug!("desktop_viewport: x: {}, y: {}", x, y);
+        Ok((x, y))
+    }
+
+    /// Determine if the window manager uses the viewport model i.e. a single large desktop that
+    /// is bigger than the screen with the screen acting as a viewport into it, as done by compiz.
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.is_viewport_model();
+    /// ```
+    pub(crate) fn is_viewport_model(&self) -> bool {
+        if self.desktops > 1 || !self.is_supported(self.atoms._NET_DESKTOP_VIEWPORT) {
+            return false;
+        }
+        self.desktop_geometry().is_ok_and(|(w, h)| w > self.width || h > self.height)
+    }
+
+    /// Get the desktop for the given screen coordinate. On viewport based window managers this is
+    /// the viewport cell within the large desktop that contains the coordinate, otherwise the
+    /// current desktop.
+    /// * Returns non zero based desktop number
+    ///
+    /// ### Arguments
+    /// * `x` - x coordinate relative to the screen
+    /// * `y` - y coordinate relative to the screen
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.desktop_for_coordinate(100, 100).unwrap();
+    /// ```
+    pub(crate) fn desktop_for_coordinate(&self, x: i32, y: i32) -> WmCtlResult<u32> {
+        if !self.is_viewport_model() {
+            return self.active_desktop();
+        }
+        let desktop = self.desktop_geometry()?;
+        let viewport = self.desktop_viewport()?;
+        let cell = viewport_cell(viewport, desktop, (self.width, self.height), x, y);
+        debug!("desktop_for_coordinate: x: {}, y: {}, desktop: {}", x, y, cell + 1);
+        Ok(cell + 1)
+    }
+
     /// Get windows optionally all
     /// * when all is true for some reason the window state is not correctly returned
     /// * when all is true the parent window is the root window for all windows
@@ -441,31 +1369,125 @@ impl WinMgr {
         Ok(pid as i32)
     }
 
+    /// Get the hostname of the machine the window's client is running on
+    /// * Returns PropertyNotFound when the client doesn't set WM_CLIENT_MACHINE
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.window_client_machine(1234)
+    /// ```
+    pub(crate) fn window_client_machine(&self, id: u32) -> WmCtlResult<String> {
+        // Defined as: WM_CLIENT_MACHINE, STRING
+        let reply = self
+            .conn
+            .get_property(false, id, AtomEnum::WM_CLIENT_MACHINE, AtomEnum::STRING, 0, u32::MAX)?
+            .reply()?;
+        if reply.type_ == x11rb::NONE {
+            return Err(WmCtlError::PropertyNotFound("WM_CLIENT_MACHINE".to_owned()).into());
+        }
+        let host = str::from_utf8(&reply.value)?.trim_end_matches('\0').to_owned();
+        debug!("win_client_machine: id: {}, host: {}", id, host);
+        Ok(host)
+    }
+
+    /// Get the time of the last user activity in the window. Some applications e.g. Firefox store
+    /// the time on a separate window pointed to by _NET_WM_USER_TIME_WINDOW to avoid waking up
+    /// clients watching the main window, in which case the time is read from that window instead.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.window_user_time(1234)
+    /// ```
+    pub(crate) fn window_user_time(&self, id: u32) -> WmCtlResult<u32> {
+        // Defined as: _NET_WM_USER_TIME_WINDOW, WINDOW/32
+        let reply = self
+            .conn
+            .get_property(false, id, self.atoms._NET_WM_USER_TIME_WINDOW, AtomEnum::WINDOW, 0, u32::MAX)?
+            .reply()?;
+        let time_win = reply.value32().and_then(|mut x| x.next()).unwrap_or(id);
+
+        // Defined as: _NET_WM_USER_TIME, CARDINAL/32
+        let reply = self
+            .conn
+            .get_property(false, time_win, self.atoms._NET_WM_USER_TIME, AtomEnum::CARDINAL, 0, u32::MAX)?
+            .reply()?;
+        let time = reply
+            .value32()
+            .and_then(|mut x| x.next())
+            .ok_or(WmCtlError::PropertyNotFound("_NET_WM_USER_TIME".to_owned()))?;
+        debug!("win_user_time: id: {}, time_win: {}, time: {}", id, time_win, time);
+        Ok(time)
+    }
+
+    /// Get the window's ICCCM size hints
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let hints = wm.window_normal_hints(1234).unwrap();
+    /// ```
+    pub(crate) fn window_normal_hints(&self, id: u32) -> WmCtlResult<SizeHints> {
+        // Defined as: WM_NORMAL_HINTS flags, pad[4], min_width, min_height, max_width, max_height,
+        // width_inc, height_inc, min_aspect[2], max_aspect[2], base_width, base_height, win_gravity,
+        // WM_SIZE_HINTS[18]/32 where flags is a bit mask of the values declared.
+        let reply = self
+            .conn
+            .get_property(false, id, AtomEnum::WM_NORMAL_HINTS, AtomEnum::WM_SIZE_HINTS, 0, u32::MAX)?
+            .reply()?;
+        let values =
+            reply.value32().ok_or(WmCtlError::PropertyNotFound("WM_NORMAL_HINTS".to_owned()))?.collect::<Vec<_>>();
+        let hints = SizeHints::from(&values);
+        debug!("win_normal_hints: id: {}, hints: {:?}", id, hints);
+        Ok(hints)
+    }
+
     /// Get window name
+    /// * Returns an empty name if a name property exists but is empty
+    /// * Returns PropertyNotFound only if no name property exists
     ///
     /// ### Arguments
     /// * `id` - id of the window to manipulate
+    /// * `deep` - when the window's own name properties are empty fall back on the _NET_WM_NAME of
+    ///   its immediate children as some apps e.g. certain Java/SWT ones only name a child window
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let wm = WinMgr::connect().unwrap();
-    /// wm.window_name(1234)
+    /// wm.window_name(1234, false)
     /// ```
-    pub(crate) fn window_name(&self, id: u32) -> WmCtlResult<String> {
+    pub(crate) fn window_name(&self, id: u32, deep: bool) -> WmCtlResult<String> {
         // Defined as: _NET_WM_NAME, UTF8_STRING
         // which means when retrieving the value via `get_property` that we need to use a `self.atoms._NET_WM_NAME`
         // request message with a `AtomEnum::UTF8_STRING` type response and we can use the `reply.value` accessor to
         // retrieve the value.
 
+        // Track if any name property exists as windows may legitimately have an empty name
+        let mut present = false;
+
         // First try the _NET_WM_VISIBLE_NAME
         let reply = self
             .conn
             .get_property(false, id, self.atoms._NET_WM_VISIBLE_NAME, self.atoms.UTF8_STRING, 0, u32::MAX)?
             .reply()?;
         if reply.type_ != x11rb::NONE {
+            present = true;
             if let Ok(value) = str::from_utf8(&reply.value) {
-                if value != "" {
+                if !value.is_empty() {
                     debug!("win_name: using _NET_WM_VISIBLE_NAME for: {}", value);
                     return Ok(value.to_owned());
                 }
@@ -478,8 +1500,9 @@ impl WinMgr {
             .get_property(false, id, self.atoms._NET_WM_NAME, self.atoms.UTF8_STRING, 0, u32::MAX)?
             .reply()?;
         if reply.type_ != x11rb::NONE {
+            present = true;
             if let Ok(value) = str::from_utf8(&reply.value) {
-                if value != "" {
+                if !value.is_empty() {
                     debug!("win_name: using _NET_WM_NAME for: {}", value);
                     return Ok(value.to_owned());
                 }
@@ -490,15 +1513,42 @@ impl WinMgr {
         let reply =
             self.conn.get_property(false, id, AtomEnum::WM_NAME, AtomEnum::STRING, 0, u32::MAX)?.reply()?;
         if reply.type_ != x11rb::NONE {
+            present = true;
             if let Ok(value) = str::from_utf8(&reply.value) {
-                if value != "" {
+                if !value.is_empty() {
                     debug!("win_name: using WM_NAME for: {}", value);
                     return Ok(value.to_owned());
                 }
             }
         }
 
-        // No valid name was found
+        // Fall back on the first named child requesting all names before waiting on any replies
+        if deep {
+            let children = self.conn.query_tree(id)?.reply()?.children;
+            let cookies = children
+                .iter()
+                .map(|x| {
+                    self.conn.get_property(false, *x, self.atoms._NET_WM_NAME, self.atoms.UTF8_STRING, 0, u32::MAX)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            for (child, cookie) in children.iter().zip(cookies) {
+                let Ok(reply) = cookie.reply() else { continue };
+                if let Ok(value) = str::from_utf8(&reply.value) {
+                    if !value.is_empty() {
+                        debug!("win_name: using _NET_WM_NAME of child: {} for: {}", child, value);
+                        return Ok(value.to_owned());
+                    }
+                }
+            }
+        }
+
+        // A name property exists but is empty
+        if present {
+            debug!("win_name: using empty name");
+            return Ok(String::new());
+        }
+
+        // No name property was found
         Err(WmCtlError::PropertyNotFound("_NET_WM_NAME | _WM_NAME".to_owned()).into())
     }
 
@@ -523,6 +1573,84 @@ impl WinMgr {
         Ok(class)
     }
 
+    /// Get window instance which is the first string of WM_CLASS typically set from the
+    /// application's command line name
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.window_instance(1234)
+    /// ```
+    pub(crate) fn window_instance(&self, id: u32) -> WmCtlResult<String> {
+        let reply =
+            self.conn.get_property(false, id, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, u32::MAX)?.reply()?;
+
+        // Extract the first null terminated string
+        let iter = reply.value.into_iter().take_while(|x| *x != 0);
+        let instance = str::from_utf8(&iter.collect::<Vec<_>>())?.to_owned();
+        debug!("win_instance: id: {}, instance: {}", id, instance);
+        Ok(instance)
+    }
+
+    /// Get window role which applications set to tell apart their windows across sessions
+    /// * Returns PropertyNotFound when the window doesn't set a role to tell it apart from an empty role
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.window_role(1234)
+    /// ```
+    pub(crate) fn window_role(&self, id: u32) -> WmCtlResult<String> {
+        // Defined as: WM_WINDOW_ROLE, STRING
+        let reply = self
+            .conn
+            .get_property(false, id, self.atoms.WM_WINDOW_ROLE, AtomEnum::STRING, 0, u32::MAX)?
+            .reply()?;
+        if reply.type_ == x11rb::NONE {
+            return Err(WmCtlError::PropertyNotFound("WM_WINDOW_ROLE".to_owned()).into());
+        }
+        let role = str::from_utf8(&reply.value)?.trim_end_matches('\0').to_owned();
+        debug!("win_role: id: {}, role: {}", id, role);
+        Ok(role)
+    }
+
+    /// Get the ICCCM state the window manager set on the window along with its icon window
+    /// * Returns withdrawn when the window manager hasn't set the state e.g. for unmanaged windows
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Returns
+    /// * `(state, icon)` where icon is the window used as the icon if any
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let (state, icon) = wm.window_wm_state(1234).unwrap();
+    /// ```
+    pub(crate) fn window_wm_state(&self, id: u32) -> WmCtlResult<(IcccmState, Option<u32>)> {
+        // Defined as: WM_STATE, WM_STATE/32
+        // which holds the state followed by the icon window
+        let reply = self.conn.get_property(false, id, self.atoms.WM_STATE, self.atoms.WM_STATE, 0, 2)?.reply()?;
+        let values = reply.value32().map(|x| x.collect::<Vec<_>>()).unwrap_or_default();
+        let state = match values.first() {
+            Some(&x) => IcccmState::from(x)?,
+            None => IcccmState::Withdrawn,
+        };
+        let icon = values.get(1).copied().filter(|x| *x != x11rb::NONE);
+        debug!("win_wm_state: id: {}, state: {}, icon: {:?}", id, state, icon);
+        Ok((state, icon))
+    }
+
     /// Get window kind
     ///
     /// ### Arguments
@@ -582,6 +1710,59 @@ impl WinMgr {
         Ok(states)
     }
 
+    /// Get the actions the window manager allows for the window. Actions not defined by the EWMH
+    /// spec are skipped.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.window_allowed_actions(1234)
+    /// ```
+    pub(crate) fn window_allowed_actions(&self, id: u32) -> WmCtlResult<Vec<WinAction>> {
+        // Defined as: _NET_WM_ALLOWED_ACTIONS, ATOM[]
+        let reply = self
+            .conn
+            .get_property(false, id, self.atoms._NET_WM_ALLOWED_ACTIONS, AtomEnum::ATOM, 0, u32::MAX)?
+            .reply()?;
+        let actions = reply
+            .value32()
+            .map(|x| x.filter_map(|val| WinAction::from(&self.atoms, val).ok()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        debug!("win_allowed_actions: id: {}, actions: {:?}", id, actions);
+        Ok(actions)
+    }
+
+    /// Set the actions the window manager should allow for the window. This is intended for
+    /// windows created by the caller as window managers maintain the property for other windows.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `actions` - actions to allow
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.set_allowed_actions(1234, &[WinAction::Move, WinAction::Resize]).unwrap();
+    /// ```
+    pub(crate) fn set_allowed_actions(&self, id: u32, actions: &[WinAction]) -> WmCtlResult<()> {
+        let values = actions.iter().map(|x| x.atom(&self.atoms)).collect::<Vec<_>>();
+        self.conn.change_property32(
+            PropMode::REPLACE,
+            id,
+            self.atoms._NET_WM_ALLOWED_ACTIONS,
+            AtomEnum::ATOM,
+            &values,
+        )?;
+        self.conn.flush()?;
+        debug!("set_allowed_actions: id: {}, actions: {:?}", id, actions);
+        Ok(())
+    }
+
     /// Get window parent
     ///
     /// ### Arguments
@@ -601,6 +1782,35 @@ impl WinMgr {
         Ok(crate::Window::new(parent_id))
     }
 
+    /// Get the chain of parents of the window from its immediate parent up to but not including
+    /// the root window e.g. the window manager's frame windows of a reparented client. The walk is
+    /// capped in depth to guard against a broken tree.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.window_ancestors(1234)
+    /// ```
+    pub(crate) fn window_ancestors(&self, id: u32) -> WmCtlResult<Vec<u32>> {
+        const MAX_DEPTH: usize = 64;
+        let mut ancestors = vec![];
+        let mut current = id;
+        while ancestors.len() < MAX_DEPTH {
+            let parent = self.conn.query_tree(current)?.reply()?.parent;
+            if parent == self.root || parent == x11rb::NONE || parent == id || ancestors.contains(&parent) {
+                break;
+            }
+            ancestors.push(parent);
+            current = parent;
+        }
+        debug!("win_ancestors: id: {}, ancestors: {:?}", id, ancestors);
+        Ok(ancestors)
+    }
+
     /// Get window desktop
     /// * Returns non zero based desktop number
     ///
@@ -630,10 +1840,34 @@ impl WinMgr {
         }
 
         debug!("win_desktop: id: {}, desktop: {}", id, desktop);
-        Ok(desktop as i32)
+        Ok(desktop)
     }
 
-    /// Get window visual geometry.
+    /// Get the desktop the window is effectively on. For viewport based window managers
+    /// `_NET_WM_DESKTOP` will always report the single large desktop so instead the desktop is
+    /// computed from the viewport cell the window's center lies in.
+    /// * Returns non zero based desktop number
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.window_effective_desktop(1234)
+    /// ```
+    pub(crate) fn window_effective_desktop(&self, id: u32) -> WmCtlResult<i32> {
+        if !self.is_viewport_model() {
+            return self.window_desktop(id);
+        }
+        let (x, y, w, h) = self.window_geometry(id)?;
+        let desktop = self.desktop_for_coordinate(x + w as i32 / 2, y + h as i32 / 2)?;
+        debug!("win_effective_desktop: id: {}, desktop: {}", id, desktop);
+        Ok(desktop as i32)
+    }
+
+    /// Get window visual geometry.
     /// Geometry is a calculated value that represents the window's size and position including it's
     /// frame or visually perceived frame. Be careful in calculating from this value as frame/application
     /// borders are added and subtracted and positioning changed in different uses cases called out
@@ -662,27 +1896,10 @@ impl WinMgr {
     /// let (x, y, w, h) = wm.window_geometry(1234).unwrap()
     /// ```
     pub(crate) fn window_visual_geometry(&self, id: u32) -> WmCtlResult<(i32, i32, u32, u32)> {
-        let (mut x, mut y, mut w, mut h) = self.window_geometry(id)?;
-
-        // Account for CSD borders
-        let mut is_gtk = false;
-        if let Ok(b) = self.window_gtk_borders(id) {
-            if b.any() {
-                w = w - b.l - b.r;
-                h = h - b.t - b.b;
-                x = x + b.l as i32;
-                y = y + b.t as i32;
-                is_gtk = true;
-            }
-        }
-        if !is_gtk {
-            if let Ok(b) = self.window_borders(id) {
-                w = w + b.l + b.r;
-                h = h + b.t + b.b;
-                x = x - b.l as i32;
-                y = y - b.t as i32;
-            }
-        }
+        let geometry = self.window_geometry(id)?;
+        let borders = self.window_borders(id).unwrap_or_default();
+        let gtk_borders = self.window_gtk_borders(id).unwrap_or_default();
+        let (x, y, w, h) = visual_geometry(geometry, &borders, &gtk_borders);
 
         debug!("win_geometry: id: {}, x: {}, y: {}, w: {}, h: {}", id, x, y, w, h);
         Ok((x, y, w, h))
@@ -791,6 +2008,110 @@ impl WinMgr {
         false
     }
 
+    /// Get the GTK application id e.g. `org.gnome.Nautilus` which is a more stable way to identify
+    /// an application than its class
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let app_id = wm.window_gtk_app_id(12345).unwrap();
+    /// ```
+    pub(crate) fn window_gtk_app_id(&self, id: u32) -> WmCtlResult<Option<String>> {
+        // Defined as: _GTK_APPLICATION_ID, UTF8_STRING
+        // which will only be set by GTK applications so a missing property is expected for others.
+        let reply = self
+            .conn
+            .get_property(false, id, self.atoms._GTK_APPLICATION_ID, self.atoms.UTF8_STRING, 0, u32::MAX)?
+            .reply()?;
+        let app_id = match reply.type_ {
+            x11rb::NONE => None,
+            _ => Some(str::from_utf8(&reply.value)?.to_owned()).filter(|x| !x.is_empty()),
+        };
+        debug!("win_gtk_app_id: id: {}, app_id: {:?}", id, app_id);
+        Ok(app_id)
+    }
+
+    /// Get the window's opacity normalized from 0.0 fully transparent to 1.0 fully opaque
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let opacity = wm.window_opacity(12345).unwrap();
+    /// ```
+    pub(crate) fn window_opacity(&self, id: u32) -> WmCtlResult<f64> {
+        // Defined as: _NET_WM_WINDOW_OPACITY, CARDINAL/32
+        // which is scaled from 0 to 0xFFFFFFFF and when not set the window is fully opaque.
+        let reply = self
+            .conn
+            .get_property(false, id, self.atoms._NET_WM_WINDOW_OPACITY, AtomEnum::CARDINAL, 0, u32::MAX)?
+            .reply()?;
+        let opacity = reply.value32().and_then(|mut x| x.next()).map_or(1.0, |x| x as f64 / u32::MAX as f64);
+        debug!("win_opacity: id: {}, opacity: {}", id, opacity);
+        Ok(opacity)
+    }
+
+    /// Set the window's opacity from 0.0 fully transparent to 1.0 fully opaque. Use
+    /// `clear_window_opacity` to remove the opacity altogether. Has no visible effect unless a
+    /// compositing manager is running.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `opacity` - opacity to set clamped to 0.0 - 1.0
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.set_window_opacity(12345, 0.8).unwrap();
+    /// ```
+    pub(crate) fn set_window_opacity(&self, id: u32, opacity: f64) -> WmCtlResult<()> {
+        if !self.compositing {
+            return Err(WmCtlError::CompositorNotFound.into());
+        }
+
+        // Defined as: _NET_WM_WINDOW_OPACITY, CARDINAL/32
+        let opacity = opacity.clamp(0.0, 1.0);
+        let value = (opacity * u32::MAX as f64) as u32;
+        self.conn.change_property32(
+            PropMode::REPLACE,
+            id,
+            self.atoms._NET_WM_WINDOW_OPACITY,
+            AtomEnum::CARDINAL,
+            &[value],
+        )?;
+        self.conn.flush()?;
+        debug!("set_opacity: id: {}, opacity: {}", id, opacity);
+        Ok(())
+    }
+
+    /// Remove the window's opacity returning it to the opacity decided by the compositing manager.
+    /// This differs from setting an opacity of 1.0 for compositors whose rules key off the presence
+    /// of the property.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.clear_window_opacity(12345).unwrap();
+    /// ```
+    pub(crate) fn clear_window_opacity(&self, id: u32) -> WmCtlResult<()> {
+        self.conn.delete_property(id, self.atoms._NET_WM_WINDOW_OPACITY)?;
+        self.conn.flush()?;
+        debug!("clear_opacity: id: {}", id);
+        Ok(())
+    }
+
     /// Get GNOME window borders
     ///
     /// ### Arguments
@@ -862,6 +2183,23 @@ impl WinMgr {
         Ok(props)
     }
 
+    /// Get the names of all the properties set on the root window as a sorted list e.g. to discover
+    /// what the window manager publishes beyond what is modeled
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.root_properties().unwrap();
+    /// ```
+    pub(crate) fn root_properties(&self) -> WmCtlResult<Vec<String>> {
+        let reply = self.conn.list_properties(self.root)?.reply()?;
+        let mut names = self.atom_names(&reply.atoms)?.into_iter().filter(|x| !x.is_empty()).collect::<Vec<_>>();
+        names.sort();
+        debug!("root_properties: root: {}, count: {}", self.root, names.len());
+        Ok(names)
+    }
+
     /// Get window attribrtes
     ///
     /// ### Arguments
@@ -881,7 +2219,135 @@ impl WinMgr {
             id, attr.win_gravity, attr.bit_gravity
         );
         //Ok((Class::from(attr.class.into())?, crate::MapState::from(attr.map_state.into())?))
-        Ok(crate::MapState::from(attr.map_state.into())?)
+        crate::MapState::from(attr.map_state.into())
+    }
+
+    /// Get the events selected on the window by all clients and by this client
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let masks = wm.window_event_mask(12345).unwrap();
+    /// ```
+    pub(crate) fn window_event_mask(&self, id: u32) -> WmCtlResult<EventMasks> {
+        let attr = self.conn.get_window_attributes(id)?.reply()?;
+        let masks = EventMasks {
+            all: attr.all_event_masks.into(),
+            yours: attr.your_event_mask.into(),
+        };
+        debug!("win_event_mask: id: {}, all: {:#x}, yours: {:#x}", id, masks.all, masks.yours);
+        Ok(masks)
+    }
+
+    /// Check if the window is viewable i.e. it is mapped and all of its ancestors are mapped
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let viewable = wm.window_is_viewable(12345).unwrap();
+    /// ```
+    pub(crate) fn window_is_viewable(&self, id: u32) -> WmCtlResult<bool> {
+        let attr = self.conn.get_window_attributes(id)?.reply()?;
+        let viewable = attr.map_state == x11rb::protocol::xproto::MapState::VIEWABLE;
+        debug!("win_is_viewable: id: {}, viewable: {}", id, viewable);
+        Ok(viewable)
+    }
+
+    /// Get the window's visual depth in bits
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let depth = wm.window_visual_depth(12345).unwrap();
+    /// ```
+    pub(crate) fn window_visual_depth(&self, id: u32) -> WmCtlResult<u8> {
+        let depth = self.conn.get_geometry(id)?.reply()?.depth;
+        debug!("win_visual_depth: id: {}, depth: {}", id, depth);
+        Ok(depth)
+    }
+
+    /// Check if the window uses a 32 bit ARGB visual and can therefore actually be transparent.
+    /// The window's visual is resolved against the screen's list of visuals to find its depth.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let argb = wm.window_is_argb(12345).unwrap();
+    /// ```
+    pub(crate) fn window_is_argb(&self, id: u32) -> WmCtlResult<bool> {
+        let visual = self.conn.get_window_attributes(id)?.reply()?.visual;
+        let depth = self.conn.setup().roots[self.screen]
+            .allowed_depths
+            .iter()
+            .find(|x| x.visuals.iter().any(|v| v.visual_id == visual))
+            .map_or(0, |x| x.depth);
+        debug!("win_is_argb: id: {}, visual: {}, depth: {}", id, visual, depth);
+        Ok(depth == 32)
+    }
+
+    /// Capture the window's contents as 8 bit RGBA. The window must be viewable and any parts of
+    /// it that are obscured or off screen will contain undefined contents unless a compositor is
+    /// redirecting the window.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to capture
+    ///
+    /// ### Returns
+    /// * `(w, h, rgba)` of the captured window
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let (w, h, rgba) = wm.window_image(12345).unwrap();
+    /// ```
+    #[cfg(feature = "image")]
+    pub(crate) fn window_image(&self, id: u32) -> WmCtlResult<(u32, u32, Vec<u8>)> {
+        let geometry = self.conn.get_geometry(id)?.reply()?;
+        let (w, h) = (geometry.width, geometry.height);
+        let image = self.conn.get_image(ImageFormat::Z_PIXMAP, id, 0, 0, w, h, u32::MAX)?.reply()?;
+
+        // Resolve the pixel layout from the image's depth and visual
+        let setup = self.conn.setup();
+        let pixmap = setup
+            .pixmap_formats
+            .iter()
+            .find(|x| x.depth == image.depth)
+            .ok_or(WmCtlError::Unsupported(format!("image depth {}", image.depth)))?;
+        let visual = setup.roots[self.screen]
+            .allowed_depths
+            .iter()
+            .flat_map(|x| x.visuals.iter())
+            .find(|x| x.visual_id == image.visual)
+            .ok_or(WmCtlError::Unsupported(format!("image visual {}", image.visual)))?;
+        let format = crate::png::PixelFormat {
+            depth: image.depth,
+            bits_per_pixel: pixmap.bits_per_pixel,
+            scanline_pad: pixmap.scanline_pad,
+            msb_first: setup.image_byte_order == ImageOrder::MSB_FIRST,
+            red_mask: visual.red_mask,
+            green_mask: visual.green_mask,
+            blue_mask: visual.blue_mask,
+        };
+        debug!("window_image: id: {}, size: {}x{}, format: {:?}", id, w, h, format);
+        let rgba = crate::png::to_rgba(&image.data, w as u32, h as u32, &format)?;
+        Ok((w as u32, h as u32, rgba))
     }
 
     /// Map the window on the screen
@@ -929,7 +2395,7 @@ impl WinMgr {
         Ok(())
     }
 
-    /// focus the window and bring it to the front of the stacking order
+    /// Make the window fullscreen covering the entire monitor including any panels
     ///
     /// ### Arguments
     /// * `id` - id of the window to manipulate
@@ -938,28 +2404,42 @@ impl WinMgr {
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let wm = WinMgr::connect().unwrap();
-    /// wm.focus_window(1234).unwrap();
+    /// wm.fullscreen_window(12345).unwrap();
     /// ```
-    pub(crate) fn focus_window(&self, id: u32) -> WmCtlResult<()> {
+    pub(crate) fn fullscreen_window(&self, id: u32) -> WmCtlResult<()> {
         self.send_event(ClientMessageEvent::new(
             32,
             id,
-            self.atoms._NET_ACTIVE_WINDOW,
-            [
-                0,
-                0,
-                0,
-                0,
-                0,
-            ],
+            self.atoms._NET_WM_STATE,
+            [WINDOW_STATE_ACTION_ADD, self.atoms._NET_WM_STATE_FULLSCREEN, 0, 0, 0],
         ))?;
+        debug!("fullscreen: id: {}", id);
+        Ok(())
+    }
 
-        self.conn.configure_window(id, &ConfigureWindowAux::new().stack_mode(StackMode::ABOVE))?;
-        debug!("focus: id: {}", id);
+    /// Take the window out of fullscreen restoring its previous geometry
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.unfullscreen_window(12345).unwrap();
+    /// ```
+    pub(crate) fn unfullscreen_window(&self, id: u32) -> WmCtlResult<()> {
+        self.send_event(ClientMessageEvent::new(
+            32,
+            id,
+            self.atoms._NET_WM_STATE,
+            [WINDOW_STATE_ACTION_REMOVE, self.atoms._NET_WM_STATE_FULLSCREEN, 0, 0, 0],
+        ))?;
+        debug!("unfullscreen: id: {}", id);
         Ok(())
     }
 
-    /// Remove the MaxVert and MaxHorz states
+    /// Toggle the window in and out of fullscreen using the window manager's native toggle action
     ///
     /// ### Arguments
     /// * `id` - id of the window to manipulate
@@ -968,129 +2448,1168 @@ impl WinMgr {
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let wm = WinMgr::connect().unwrap();
-    /// wm.unmaximize_window().unwrap();
+    /// wm.toggle_fullscreen_window(12345).unwrap();
     /// ```
-    pub(crate) fn unmaximize_window(&self, id: u32) -> WmCtlResult<()> {
+    pub(crate) fn toggle_fullscreen_window(&self, id: u32) -> WmCtlResult<()> {
         self.send_event(ClientMessageEvent::new(
             32,
             id,
             self.atoms._NET_WM_STATE,
-            [
-                WINDOW_STATE_ACTION_REMOVE,
-                self.atoms._NET_WM_STATE_MAXIMIZED_HORZ,
-                self.atoms._NET_WM_STATE_MAXIMIZED_VERT,
-                0,
-                0,
-            ],
+            [WINDOW_STATE_ACTION_TOGGLE, self.atoms._NET_WM_STATE_FULLSCREEN, 0, 0, 0],
         ))?;
-        debug!("unmaximize: id: {}", id);
+        debug!("toggle_fullscreen: id: {}", id);
         Ok(())
     }
 
-    /// Move and resize window
+    /// focus the window and bring it to the front of the stacking order
     ///
     /// ### Arguments
     /// * `id` - id of the window to manipulate
     ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.focus_window(1234).unwrap();
+    /// ```
+    pub(crate) fn focus_window(&self, id: u32) -> WmCtlResult<()> {
+        // Defined as: _NET_ACTIVE_WINDOW, source indication, timestamp, requestor's active window
+        // Window managers with focus stealing prevention ignore requests without a real timestamp
+        let time = self.server_time()?;
+        let active = self.active_window().unwrap_or_default();
+        self.send_event(ClientMessageEvent::new(32, id, self.atoms._NET_ACTIVE_WINDOW, [2, time, active, 0, 0]))?;
+
+        self.conn.configure_window(id, &ConfigureWindowAux::new().stack_mode(StackMode::ABOVE))?;
+        debug!("focus: id: {}, time: {}", id, time);
+        Ok(())
+    }
+
+    /// Ask the window manager to gracefully close the window giving the application a chance to
+    /// save its state or ask the user e.g. about unsaved changes
+    /// * Returns Unsupported when the window manager doesn't advertise _NET_CLOSE_WINDOW
+    ///
     /// ### Arguments
-    /// * `gravity` - gravity to use when resizing the window, defaults to NorthWest
-    /// * `x` - x coordinate to use for the window during positioning
-    /// * `y` - y coordinate to use for the window during positioning
-    /// * `w` - width to resize the window to
-    /// * `h` - height to resize the window to
+    /// * `id` - id of the window to manipulate
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let wm = WinMgr::connect().unwrap();
-    /// let win = window(12345);
-    /// win.move_resize_win(None, Some(0), Some(0), Some(500), Some(500)).unwrap();
+    /// wm.close_window(1234).unwrap();
     /// ```
-    pub(crate) fn move_resize_window(
-        &self, id: u32, gravity: Option<u32>, x: Option<i32>, y: Option<i32>, w: Option<u32>, h: Option<u32>,
-    ) -> WmCtlResult<()> {
-        self.conn.configure_window(id, &ConfigureWindowAux::new().width(w).height(h).x(x).y(y))?;
-        self.conn.flush()?; // Requires the flush to work
-
-        // // Old implementation below doesn't allow for negative (x, y) coordinates
-        // // ----------------------------------------------------------------
-        // // Construct the move resize message
-        // // Gravity is defined as the lower byte of the move resize flags 32bit value
-        // // https://tronche.com/gui/x/xlib/window/attributes/gravity.html
-        // // Defines how the window will shift as it grows or shrinks during a shape change operation.
-        // // The default value is NorthWest which means that the window will grow to the right and down
-        // // and will shrink up and left. By changing this to center you can get a more distributed growth
-        // // or shrink perception.
-        // let mut flags = gravity.unwrap_or(0);
-
-        // // Define the second byte of the move resize flags 32bit value
-        // // Used to indicate that the associated value has been changed and needs to be acted upon
-        // if x.is_some() {
-        //     flags |= MOVE_RESIZE_WINDOW_X;
-        // }
-        // if y.is_some() {
-        //     flags |= MOVE_RESIZE_WINDOW_Y;
-        // }
-        // if w.is_some() {
-        //     flags |= MOVE_RESIZE_WINDOW_WIDTH;
-        // }
-        // if h.is_some() {
-        //     flags |= MOVE_RESIZE_WINDOW_HEIGHT;
-        // }
+    pub(crate) fn close_window(&self, id: u32) -> WmCtlResult<()> {
+        if !self.is_supported(self.atoms._NET_CLOSE_WINDOW) {
+            return Err(WmCtlError::Unsupported("_NET_CLOSE_WINDOW".to_owned()).into());
+        }
 
-        // self.send_event(ClientMessageEvent::new(
-        //     32,
-        //     id,
-        //     self.atoms._NET_MOVERESIZE_WINDOW,
-        //     [
-        //         flags,
-        //         x.and_then(|x| if x < 0 { Some(0 as u32) } else { Some(x as u32) }).unwrap_or(0),
-        //         y.and_then(|y| if y < 0 { Some(0 as u32) } else { Some(y as u32) }).unwrap_or(0),
-        //         w.unwrap_or(0),
-        //         h.unwrap_or(0),
-        //     ],
-        // ))?;
-
-        println!("move_resize: id: {}, g: {:?}, x: {:?}, y: {:?}, w: {:?}, h: {:?}", id, gravity, x, y, w, h);
+        // Defined as: _NET_CLOSE_WINDOW, timestamp, source indication
+        let time = self.server_time()?;
+        self.send_event(ClientMessageEvent::new(32, id, self.atoms._NET_CLOSE_WINDOW, [time, 2, 0, 0, 0]))?;
+        debug!("close_window: id: {}, time: {}", id, time);
         Ok(())
     }
 
-    /// Send the event ensuring that a flush is called and that the message was precisely
-    /// executed in the case of a resize/move.
+    /// Check if the window still exists i.e. it hasn't been destroyed
     ///
     /// ### Arguments
-    /// * `msg` - the client message event to send
+    /// * `id` - id of the window to check
     ///
     /// ### Examples
     /// ```ignore
     /// use libwmctl::prelude::*;
     /// let wm = WinMgr::connect().unwrap();
-    /// let flags = MOVE_RESIZE_WINDOW_WIDTH | MOVE_RESIZE_WINDOW_HEIGHT;
-    /// wm.send_event(ClientMessageEvent::new(32, win, wm.atoms._NET_MOVERESIZE_WINDOW,
-    ///     [flags, 0, 0, 500, 500])).unwrap();
+    /// let exists = wm.window_exists(1234).unwrap();
     /// ```
-    fn send_event(&self, msg: ClientMessageEvent) -> WmCtlResult<()> {
-        let mask = EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY;
-        self.conn.send_event(false, self.root, mask, &msg)?.check()?;
-        self.conn.flush()?;
-        debug!("send_event: win: {}", msg.window);
+    pub(crate) fn window_exists(&self, id: u32) -> WmCtlResult<bool> {
+        // Requests on a destroyed window fail with a BadWindow error
+        let exists = self.conn.get_window_attributes(id)?.reply().is_ok();
+        debug!("window_exists: id: {}, exists: {}", id, exists);
+        Ok(exists)
+    }
 
-        // I've found that Xfwm4 does not precisely resize a window on the first request. It may be
-        // this is a function of decorating the window during a redraw. At any rate because of this
-        // unfortunate shortcoming we have to send the event a second time.
-        if msg.type_ == self.atoms._NET_MOVERESIZE_WINDOW {
-            std::thread::sleep(std::time::Duration::from_millis(50));
-            self.conn.send_event(false, self.root, mask, &msg)?.check()?;
-            self.conn.flush()?;
-            debug!("send_event: win: {}", msg.window);
-        }
+    /// Ask the window manager to minimize the window via the ICCCM WM_CHANGE_STATE message as
+    /// clients aren't meant to set the _NET_WM_STATE_HIDDEN state directly
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.minimize_window(1234).unwrap();
+    /// ```
+    pub(crate) fn minimize_window(&self, id: u32) -> WmCtlResult<()> {
+        // Defined as: WM_CHANGE_STATE, IconicState
+        self.send_event(ClientMessageEvent::new(32, id, self.atoms.WM_CHANGE_STATE, [3, 0, 0, 0, 0]))?;
+        debug!("minimize_window: id: {}", id);
         Ok(())
     }
 
-    // Helper method to print out the data type
-    // println!("DataType NET: {:?}", AtomEnum::from(reply.type_ as u8));
-    #[allow(dead_code)]
-    fn print_data_type(reply: &GetPropertyReply) {
-        println!("DataType: {:?}", AtomEnum::from(reply.type_ as u8));
+    /// Restore the minimized window by activating it which maps and focuses the window
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.unminimize_window(1234).unwrap();
+    /// ```
+    pub(crate) fn unminimize_window(&self, id: u32) -> WmCtlResult<()> {
+        self.focus_window(id)?;
+        debug!("unminimize_window: id: {}", id);
+        Ok(())
+    }
+
+    /// Forcibly disconnect the client owning the window as done by `xkill`. This destroys all of
+    /// the client's windows without giving the application a chance to save its state and should
+    /// only be used as a last resort when a graceful close has been ignored e.g. a hung application.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.kill_client(1234).unwrap();
+    /// ```
+    pub(crate) fn kill_client(&self, id: u32) -> WmCtlResult<()> {
+        self.conn.kill_client(id)?.check()?;
+        debug!("kill_client: id: {}", id);
+        Ok(())
+    }
+
+    /// Check if the application owning the window is still responding by sending it a _NET_WM_PING
+    /// and waiting for the application to echo it back to the root window. The root window's event
+    /// mask is restored afterwards and other events queued while waiting are held back for
+    /// `poll_event` and the window watchers.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `timeout` - how long to wait for the reply
+    ///
+    /// ### Returns
+    /// * `true` if the application replied in time or `false` if it timed out
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.ping(1234, Duration::from_secs(2)).unwrap();
+    /// ```
+    pub(crate) fn ping(&self, id: u32, timeout: Duration) -> WmCtlResult<bool> {
+        // Defined as: WM_PROTOCOLS, ATOM[]/32
+        // Only applications advertising _NET_WM_PING will ever reply
+        let reply =
+            self.conn.get_property(false, id, self.atoms.WM_PROTOCOLS, AtomEnum::ATOM, 0, u32::MAX)?.reply()?;
+        if !reply.value32().is_some_and(|mut x| x.any(|x| x == self.atoms._NET_WM_PING)) {
+            return Err(WmCtlError::PropertyNotFound("_NET_WM_PING".to_owned()).into());
+        }
+
+        // Replies are sent to the root window with the substructure masks so add it to the mask
+        // selected by this client for the duration of the ping
+        let mask = self.conn.get_window_attributes(self.root)?.reply()?.your_event_mask;
+        self.conn
+            .change_window_attributes(
+                self.root,
+                &ChangeWindowAttributesAux::new().event_mask(mask | EventMask::SUBSTRUCTURE_NOTIFY),
+            )?
+            .check()?;
+        let replied = self.ping_window(id, timeout);
+        self.conn
+            .change_window_attributes(self.root, &ChangeWindowAttributesAux::new().event_mask(mask))?
+            .check()?;
+        let replied = replied?;
+        debug!("ping: id: {}, replied: {}", id, replied);
+        Ok(replied)
+    }
+
+    /// Send the _NET_WM_PING and wait for the reply once the root window's event mask is set
+    fn ping_window(&self, id: u32, timeout: Duration) -> WmCtlResult<bool> {
+        // Defined as: WM_PROTOCOLS, _NET_WM_PING, timestamp, window, 0, 0
+        // The application echos the timestamp back unchanged identifying the reply
+        let timestamp = self.server_time()?;
+        self.conn.send_event(
+            false,
+            id,
+            EventMask::NO_EVENT,
+            ClientMessageEvent::new(
+                32,
+                id,
+                self.atoms.WM_PROTOCOLS,
+                [self.atoms._NET_WM_PING, timestamp, id, 0, 0],
+            ),
+        )?;
+        self.conn.flush()?;
+
+        let matches = |event: &XEvent| match event {
+            XEvent::ClientMessage(e) => {
+                let data = e.data.as_data32();
+                e.type_ == self.atoms.WM_PROTOCOLS
+                    && data[0] == self.atoms._NET_WM_PING
+                    && data[1] == timestamp
+                    && data[2] == id
+            },
+            _ => false,
+        };
+        Ok(self.wait_event(matches, timeout)?.is_some())
+    }
+
+    /// Get the space the window reserves along the edges of the screen from _NET_WM_STRUT_PARTIAL
+    /// falling back on the older _NET_WM_STRUT which reserves the entire edge
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to query
+    ///
+    /// ### Returns
+    /// * the reserved space or `None` if the window doesn't reserve any
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let strut = wm.window_struts(1234).unwrap();
+    /// ```
+    pub(crate) fn window_struts(&self, id: u32) -> WmCtlResult<Option<Strut>> {
+        // Defined as: _NET_WM_STRUT_PARTIAL, CARDINAL[12]/32 and _NET_WM_STRUT, CARDINAL[4]/32
+        let partial =
+            self.conn.get_property(false, id, self.atoms._NET_WM_STRUT_PARTIAL, AtomEnum::CARDINAL, 0, 12)?;
+        let full = self.conn.get_property(false, id, self.atoms._NET_WM_STRUT, AtomEnum::CARDINAL, 0, 4)?;
+        let partial = partial.reply()?.value32().map(|x| x.collect::<Vec<_>>()).unwrap_or_default();
+        let full = full.reply()?.value32().map(|x| x.collect::<Vec<_>>()).unwrap_or_default();
+        let strut = self.parse_struts(&partial, &full)?;
+        debug!("win_struts: id: {}, strut: {:?}", id, strut);
+        Ok(strut)
+    }
+
+    /// Build the strut from the raw _NET_WM_STRUT_PARTIAL values preferring them over the legacy
+    /// _NET_WM_STRUT values which span the whole screen edge
+    ///
+    /// ### Arguments
+    /// * `partial` - raw _NET_WM_STRUT_PARTIAL values if any
+    /// * `full` - raw _NET_WM_STRUT values if any
+    fn parse_struts(&self, partial: &[u32], full: &[u32]) -> WmCtlResult<Option<Strut>> {
+        Ok(if partial.len() == 12 {
+            Some(Strut::from(partial)?)
+        } else if full.len() == 4 {
+            Some(Strut::from_full(full, self.width, self.height)?)
+        } else {
+            None
+        })
+    }
+
+    /// Reserve space along the edges of the screen for the window e.g. a panel or dock by setting
+    /// _NET_WM_STRUT_PARTIAL and _NET_WM_STRUT for older window managers. The window manager will
+    /// shrink the work area accordingly for other windows.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `strut` - space to reserve along each edge
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.set_strut_partial(1234, Strut { top: 30, top_end_x: 1919, ..Default::default() }).unwrap();
+    /// ```
+    pub(crate) fn set_strut_partial(&self, id: u32, strut: Strut) -> WmCtlResult<()> {
+        strut.validate()?;
+
+        // Defined as: _NET_WM_STRUT_PARTIAL, left, right, top, bottom, left_start_y, left_end_y,
+        // right_start_y, right_end_y, top_start_x, top_end_x, bottom_start_x, bottom_end_x, CARDINAL[12]/32
+        // with _NET_WM_STRUT being the first four values.
+        let values = strut.values();
+        self.conn.change_property32(
+            PropMode::REPLACE,
+            id,
+            self.atoms._NET_WM_STRUT_PARTIAL,
+            AtomEnum::CARDINAL,
+            &values,
+        )?;
+        self.conn.change_property32(
+            PropMode::REPLACE,
+            id,
+            self.atoms._NET_WM_STRUT,
+            AtomEnum::CARDINAL,
+            &values[..4],
+        )?;
+        self.conn.flush()?;
+        debug!("set_strut_partial: id: {}, strut: {:?}", id, strut);
+        Ok(())
+    }
+
+    /// Reparent the window into the given parent window at the given position relative to the
+    /// parent as done when embedding windows e.g. XEmbed based trays.
+    /// * The window manager may stop managing a window that has been reparented away from it
+    /// * Reparent sequences should be done with the server grabbed to avoid racing the window manager
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `parent` - id of the new parent window
+    /// * `x` - x coordinate relative to the new parent
+    /// * `y` - y coordinate relative to the new parent
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.reparent_window(1234, 5678, 0, 0).unwrap();
+    /// ```
+    pub(crate) fn reparent_window(&self, id: u32, parent: u32, x: i16, y: i16) -> WmCtlResult<()> {
+        self.conn.reparent_window(id, parent, x, y)?;
+        self.conn.flush()?;
+        debug!("reparent: id: {}, parent: {}, x: {}, y: {}", id, parent, x, y);
+        Ok(())
+    }
+
+    /// Get the pointer's position relative to the top left corner of the screen
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let (x, y) = wm.pointer_position().unwrap();
+    /// ```
+    pub(crate) fn pointer_position(&self) -> WmCtlResult<(i32, i32)> {
+        let reply = self.conn.query_pointer(self.root)?.reply()?;
+        let (x, y) = (reply.root_x as i32, reply.root_y as i32);
+        debug!("pointer_position: x: {}, y: {}", x, y);
+        Ok((x, y))
+    }
+
+    /// Warp the pointer to the given position relative to the window's top left corner
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `x` - x coordinate relative to the window's left edge
+    /// * `y` - y coordinate relative to the window's top edge
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.warp_pointer(1234, 10, 10).unwrap();
+    /// ```
+    pub(crate) fn warp_pointer(&self, id: u32, x: i32, y: i32) -> WmCtlResult<()> {
+        self.conn.warp_pointer(x11rb::NONE, id, 0, 0, 0, 0, x as i16, y as i16)?;
+        self.conn.flush()?;
+        debug!("warp_pointer: id: {}, x: {}, y: {}", id, x, y);
+        Ok(())
+    }
+
+    /// Raise the window to the top of the stacking order without focusing it
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.raise_window(1234).unwrap();
+    /// ```
+    pub(crate) fn raise_window(&self, id: u32) -> WmCtlResult<()> {
+        self.restack_window(id, StackMode::ABOVE)?;
+        debug!("raise: id: {}", id);
+        Ok(())
+    }
+
+    /// Lower the window to the bottom of the stacking order
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.lower_window(1234).unwrap();
+    /// ```
+    pub(crate) fn lower_window(&self, id: u32) -> WmCtlResult<()> {
+        self.restack_window(id, StackMode::BELOW)?;
+        debug!("lower: id: {}", id);
+        Ok(())
+    }
+
+    /// Restack the window relative to all its siblings. Window managers that support it are asked
+    /// via _NET_RESTACK_WINDOW as they may ignore the configure request of a managed window.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `mode` - stack mode to apply
+    fn restack_window(&self, id: u32, mode: StackMode) -> WmCtlResult<()> {
+        if self.is_supported(self.atoms._NET_RESTACK_WINDOW) {
+            // Defined as: _NET_RESTACK_WINDOW, source indication, sibling window, detail
+            return self.send_event(ClientMessageEvent::new(
+                32,
+                id,
+                self.atoms._NET_RESTACK_WINDOW,
+                [2, x11rb::NONE, u32::from(mode), 0, 0],
+            ));
+        }
+        self.conn.configure_window(id, &ConfigureWindowAux::new().stack_mode(mode))?;
+        self.conn.flush()?;
+        Ok(())
+    }
+
+    /// Remove the MaxVert and MaxHorz states
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.unmaximize_window().unwrap();
+    /// ```
+    pub(crate) fn unmaximize_window(&self, id: u32) -> WmCtlResult<()> {
+        self.send_event(ClientMessageEvent::new(
+            32,
+            id,
+            self.atoms._NET_WM_STATE,
+            [
+                WINDOW_STATE_ACTION_REMOVE,
+                self.atoms._NET_WM_STATE_MAXIMIZED_HORZ,
+                self.atoms._NET_WM_STATE_MAXIMIZED_VERT,
+                0,
+                0,
+            ],
+        ))?;
+        debug!("unmaximize: id: {}", id);
+        Ok(())
+    }
+
+    /// Add or remove the given window states. The _NET_WM_STATE message carries at most two
+    /// states so additional states are sent as additional messages.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `action` - WINDOW_STATE_ACTION_ADD or WINDOW_STATE_ACTION_REMOVE
+    /// * `states` - states to add or remove
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.set_window_state(1234, WINDOW_STATE_ACTION_ADD, &[State::Above]).unwrap();
+    /// ```
+    pub(crate) fn set_window_state(
+        &self, id: u32, action: WindowStateAction, states: &[State],
+    ) -> WmCtlResult<()> {
+        for pair in states.chunks(2) {
+            let first = pair[0].atom(&self.atoms)?;
+            let second = match pair.get(1) {
+                Some(state) => state.atom(&self.atoms)?,
+                None => 0,
+            };
+            self.send_event(ClientMessageEvent::new(
+                32,
+                id,
+                self.atoms._NET_WM_STATE,
+                [action, first, second, 0, 0],
+            ))?;
+        }
+        debug!("set_state: id: {}, action: {}, states: {:?}", id, action, states);
+        Ok(())
+    }
+
+    /// Set or clear the ICCCM urgency hint in the window's WM_HINTS property. This is the
+    /// older way of requesting attention that some window managers still rely on.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `urgent` - true to set the urgency hint, false to clear it
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.set_window_urgency(1234, true).unwrap();
+    /// ```
+    pub(crate) fn set_window_urgency(&self, id: u32, urgent: bool) -> WmCtlResult<()> {
+        let mut hints = self.window_wm_hints(id)?;
+        hints.set_urgency(urgent);
+        self.set_wm_hints(id, hints)?;
+        debug!("set_urgency: id: {}, urgent: {}", id, urgent);
+        Ok(())
+    }
+
+    /// Get the window's ICCCM WM_HINTS. Windows without the property get the default hints.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let hints = wm.window_wm_hints(1234).unwrap();
+    /// ```
+    pub(crate) fn window_wm_hints(&self, id: u32) -> WmCtlResult<WmHints> {
+        // Defined as: WM_HINTS flags, input, initial_state, icon_pixmap, icon_window, icon_x, icon_y,
+        // icon_mask, window_group, WM_HINTS[9]/32 where flags is a bit mask of the values present.
+        let reply =
+            self.conn.get_property(false, id, AtomEnum::WM_HINTS, AtomEnum::WM_HINTS, 0, u32::MAX)?.reply()?;
+        let values = reply.value32().map(|x| x.collect::<Vec<_>>()).unwrap_or_default();
+        let hints = WmHints::from(&values);
+        debug!("win_wm_hints: id: {}, hints: {:?}", id, hints);
+        Ok(hints)
+    }
+
+    /// Set the window's ICCCM WM_HINTS e.g. to control whether the window accepts input focus.
+    /// This is meant for windows created by the caller as the application owning the window
+    /// is otherwise free to overwrite them.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `hints` - hints to set
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let mut hints = WmHints::default();
+    /// hints.set_input(true);
+    /// wm.set_wm_hints(1234, hints).unwrap();
+    /// ```
+    pub(crate) fn set_wm_hints(&self, id: u32, hints: WmHints) -> WmCtlResult<()> {
+        self.conn.change_property32(
+            PropMode::REPLACE,
+            id,
+            AtomEnum::WM_HINTS,
+            AtomEnum::WM_HINTS,
+            &hints.values(),
+        )?;
+        self.conn.flush()?;
+        debug!("set_wm_hints: id: {}, hints: {:?}", id, hints);
+        Ok(())
+    }
+
+    /// Request or withdraw the user's attention for the window by setting both the EWMH
+    /// _NET_WM_STATE_DEMANDS_ATTENTION state and the ICCCM urgency hint for compatibility.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `attention` - true to demand attention, false to clear the demand
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.set_window_attention(1234, true).unwrap();
+    /// ```
+    pub(crate) fn set_window_attention(&self, id: u32, attention: bool) -> WmCtlResult<()> {
+        let action = if attention { WINDOW_STATE_ACTION_ADD } else { WINDOW_STATE_ACTION_REMOVE };
+        self.set_window_state(id, action, &[State::DemandsAttention])?;
+        self.set_window_urgency(id, attention)
+    }
+
+    /// Switch the current desktop to the given desktop. On viewport based window managers the
+    /// desktop is the viewport cell within the large desktop and the viewport is moved to it.
+    ///
+    /// ### Arguments
+    /// * `desktop` - desktop to switch to numbered from 1
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.set_active_desktop(2).unwrap();
+    /// ```
+    pub(crate) fn set_active_desktop(&self, desktop: u32) -> WmCtlResult<()> {
+        if self.is_viewport_model() {
+            let geometry = self.desktop_geometry()?;
+            let (x, y) = desktop
+                .checked_sub(1)
+                .and_then(|cell| viewport_origin(geometry, (self.width, self.height), cell))
+                .ok_or(WmCtlError::InvalidDesktop(desktop))?;
+
+            // Defined as: _NET_DESKTOP_VIEWPORT new_vx, new_vy
+            self.send_event(ClientMessageEvent::new(
+                32,
+                self.root,
+                self.atoms._NET_DESKTOP_VIEWPORT,
+                [x, y, 0, 0, 0],
+            ))?;
+            debug!("set_active_desktop: desktop: {}, viewport: ({}, {})", desktop, x, y);
+            return Ok(());
+        }
+        if desktop == 0 || desktop > self.desktops()? {
+            return Err(WmCtlError::InvalidDesktop(desktop).into());
+        }
+
+        // Defined as: _NET_CURRENT_DESKTOP new_index, timestamp
+        let time = self.server_time()?;
+        self.send_event(ClientMessageEvent::new(
+            32,
+            self.root,
+            self.atoms._NET_CURRENT_DESKTOP,
+            [desktop - 1, time, 0, 0, 0],
+        ))?;
+        debug!("set_active_desktop: desktop: {}", desktop);
+        Ok(())
+    }
+
+    /// Get the current desktop and the live number of desktops. On viewport based window managers
+    /// these are the viewport cell and the number of viewport cells within the large desktop.
+    ///
+    /// ### Returns
+    /// * `(current, count)` with the current desktop numbered from 1
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let (current, count) = wm.desktop_position().unwrap();
+    /// ```
+    pub(crate) fn desktop_position(&self) -> WmCtlResult<(u32, u32)> {
+        if self.is_viewport_model() {
+            let (w, h) = self.desktop_geometry()?;
+            let count = (w / self.width.max(1)).max(1) * (h / self.height.max(1)).max(1);
+            return Ok((self.desktop_for_coordinate(0, 0)?, count));
+        }
+        Ok((self.active_desktop()?, self.desktops()?))
+    }
+
+    /// Switch to the desktop the given number of steps away from the current desktop wrapping
+    /// around at either end
+    ///
+    /// ### Arguments
+    /// * `step` - number of desktops to move forward or backward if negative
+    ///
+    /// ### Returns
+    /// * the desktop switched to numbered from 1
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.cycle_desktop(-1).unwrap();
+    /// ```
+    pub(crate) fn cycle_desktop(&self, step: i32) -> WmCtlResult<u32> {
+        let (current, count) = self.desktop_position()?;
+        let desktop = wrap_desktop(current, count, step);
+        debug!("cycle_desktop: step: {}, from: {}, to: {}, count: {}", step, current, desktop, count);
+        self.set_active_desktop(desktop)?;
+        Ok(desktop)
+    }
+
+    /// Switch to the given desktop checking it against the live number of desktops rather than
+    /// the number of desktops when the connection was made
+    ///
+    /// ### Arguments
+    /// * `desktop` - desktop to switch to numbered from 1
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.nth_desktop(3).unwrap();
+    /// ```
+    pub(crate) fn nth_desktop(&self, desktop: u32) -> WmCtlResult<()> {
+        let (_, count) = self.desktop_position()?;
+        if desktop == 0 || desktop > count {
+            return Err(WmCtlError::InvalidDesktop(desktop).into());
+        }
+        self.set_active_desktop(desktop)
+    }
+
+    /// Request the window manager change the desktop geometry i.e. the size of the large virtual
+    /// desktop shared by all desktops. Only window managers supporting large desktops will honor it.
+    ///
+    /// ### Arguments
+    /// * `w` - width of the virtual desktop
+    /// * `h` - height of the virtual desktop
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.set_desktop_geometry(3840, 1080).unwrap();
+    /// ```
+    pub(crate) fn set_desktop_geometry(&self, w: u32, h: u32) -> WmCtlResult<()> {
+        if !self.is_supported(self.atoms._NET_DESKTOP_GEOMETRY) {
+            return Err(WmCtlError::Unsupported("_NET_DESKTOP_GEOMETRY".to_owned()).into());
+        }
+
+        // Defined as: _NET_DESKTOP_GEOMETRY new_width, new_height
+        self.send_event(ClientMessageEvent::new(
+            32,
+            self.root,
+            self.atoms._NET_DESKTOP_GEOMETRY,
+            [w, h, 0, 0, 0],
+        ))?;
+        debug!("set_desktop_geometry: w: {}, h: {}", w, h);
+        Ok(())
+    }
+
+    /// Move the window to the given desktop or to all desktops if `DESKTOP_STICKY` is given.
+    /// Managed windows are moved by asking the window manager, including those it unmapped for
+    /// being on another desktop or minimized. Unmanaged or withdrawn windows have their
+    /// _NET_WM_DESKTOP property written directly as window managers ignore the request for windows
+    /// they aren't yet managing and the property is read by the window manager on map.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `desktop` - desktop to move the window to numbered from 1 or `DESKTOP_STICKY`
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.move_window_to_desktop(1234, 2).unwrap();
+    /// ```
+    pub(crate) fn move_window_to_desktop(&self, id: u32, desktop: u32) -> WmCtlResult<()> {
+        self.move_windows_to_desktop(&[id], desktop)
+    }
+
+    /// Move all the given windows to the given desktop flushing the requests once at the end
+    ///
+    /// ### Arguments
+    /// * `ids` - ids of the windows to manipulate
+    /// * `desktop` - desktop to move the windows to numbered from 1 or `DESKTOP_STICKY`
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// wm.move_windows_to_desktop(&[1234, 5678], 2).unwrap();
+    /// ```
+    pub(crate) fn move_windows_to_desktop(&self, ids: &[u32], desktop: u32) -> WmCtlResult<()> {
+        // Viewport based window managers have a single desktop with cells acting as desktops
+        if desktop != DESKTOP_STICKY && self.is_viewport_model() {
+            return self.move_windows_to_viewport(ids, desktop);
+        }
+        if desktop != DESKTOP_STICKY && (desktop == 0 || desktop > self.desktops()?) {
+            return Err(WmCtlError::InvalidDesktop(desktop).into());
+        }
+
+        // Defined as: _NET_WM_DESKTOP new_desktop, source indication
+        let index = if desktop == DESKTOP_STICKY { DESKTOP_STICKY } else { desktop - 1 };
+        let mask = EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY;
+        let clients = self.windows(false).unwrap_or_default();
+        for &id in ids {
+            let managed = self.window_is_managed(id, &clients)?;
+            if managed {
+                let msg = ClientMessageEvent::new(32, id, self.atoms._NET_WM_DESKTOP, [index, 2, 0, 0, 0]);
+                self.conn.send_event(false, self.root, mask, msg)?;
+            } else {
+                self.conn.change_property32(
+                    PropMode::REPLACE,
+                    id,
+                    self.atoms._NET_WM_DESKTOP,
+                    AtomEnum::CARDINAL,
+                    &[index],
+                )?;
+            }
+            debug!("move_to_desktop: id: {}, desktop: {}, managed: {}", id, desktop, managed);
+        }
+        self.conn.flush()?;
+        Ok(())
+    }
+
+    /// Check if the window manager is managing the window i.e. it is listed in the given client
+    /// list or the window manager has set a WM_STATE other than withdrawn on it. Unlike the map
+    /// state this holds for windows on other desktops or minimized windows.
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to check
+    /// * `clients` - window manager client list as returned by `windows(false)`
+    fn window_is_managed(&self, id: u32, clients: &[u32]) -> WmCtlResult<bool> {
+        if clients.contains(&id) {
+            return Ok(true);
+        }
+        Ok(self.window_wm_state(id)?.0 != IcccmState::Withdrawn)
+    }
+
+    /// Move all the given windows to the given viewport cell of the large desktop on viewport based
+    /// window managers by shifting each window by the offset between its cell and the target cell
+    /// such that it keeps its position relative to the viewport.
+    ///
+    /// ### Arguments
+    /// * `ids` - ids of the windows to manipulate
+    /// * `desktop` - viewport cell to move the windows to numbered from 1
+    fn move_windows_to_viewport(&self, ids: &[u32], desktop: u32) -> WmCtlResult<()> {
+        let large = self.desktop_geometry()?;
+        let screen = (self.width, self.height);
+        if desktop == 0 || viewport_origin(large, screen, desktop - 1).is_none() {
+            return Err(WmCtlError::InvalidDesktop(desktop).into());
+        }
+
+        for &id in ids {
+            let current = self.window_effective_desktop(id)?.max(1) as u32;
+            let (dx, dy) = viewport_delta(large, screen, current - 1, desktop - 1).unwrap_or_default();
+            let gravity = match self.window_normal_hints(id) {
+                Ok(hints) if hints.has_win_gravity() => crate::Gravity::from(hints.win_gravity),
+                _ => crate::Gravity::NorthWest,
+            };
+            let (x, y, _, _) = self.window_visual_geometry(id)?;
+            let (x, y) = crate::window::translate_visible(
+                x + dx,
+                y + dy,
+                &self.window_borders(id).unwrap_or_default(),
+                &self.window_gtk_borders(id).unwrap_or_default(),
+                &gravity,
+            );
+            self.conn.configure_window(id, &ConfigureWindowAux::new().x(x).y(y))?;
+            debug!("move_to_viewport: id: {}, desktop: {}, x: {}, y: {}", id, desktop, x, y);
+        }
+        self.conn.flush()?;
+        Ok(())
+    }
+
+    /// Move and resize window
+    /// * Uses _NET_MOVERESIZE_WINDOW when the window manager advertises it which honors the gravity
+    /// * Falls back on a direct configure request for window managers that don't advertise it and
+    ///   for negative (x, y) coordinates which _NET_MOVERESIZE_WINDOW can't express
+    /// * The configure fallback bypasses the window manager's gravity handling so the gravity is
+    ///   ignored and positions are always relative to the window's top left corner
+    ///
+    /// ### Arguments
+    /// * `id` - id of the window to manipulate
+    /// * `gravity` - gravity to use when resizing the window, defaults to NorthWest
+    /// * `x` - x coordinate to use for the window during positioning
+    /// * `y` - y coordinate to use for the window during positioning
+    /// * `w` - width to resize the window to
+    /// * `h` - height to resize the window to
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let win = window(12345);
+    /// win.move_resize_win(None, Some(0), Some(0), Some(500), Some(500)).unwrap();
+    /// ```
+    pub(crate) fn move_resize_window(
+        &self, id: u32, gravity: Option<u32>, x: Option<i32>, y: Option<i32>, w: Option<u32>, h: Option<u32>,
+    ) -> WmCtlResult<()> {
+        let negative = x.is_some_and(|x| x < 0) || y.is_some_and(|y| y < 0);
+        if !negative && self.is_supported(self.atoms._NET_MOVERESIZE_WINDOW) {
+            // Gravity is defined as the lower byte of the move resize flags 32bit value
+            // https://tronche.com/gui/x/xlib/window/attributes/gravity.html
+            // Defines how the window will shift as it grows or shrinks during a shape change
+            // operation. The default value is NorthWest which means that the window will grow to
+            // the right and down and will shrink up and left.
+            let mut flags = gravity.unwrap_or(0) & 0xFF;
+
+            // Define the second byte of the move resize flags 32bit value
+            // Used to indicate that the associated value has been changed and needs to be acted upon
+            if x.is_some() {
+                flags |= MOVE_RESIZE_WINDOW_X;
+            }
+            if y.is_some() {
+                flags |= MOVE_RESIZE_WINDOW_Y;
+            }
+            if w.is_some() {
+                flags |= MOVE_RESIZE_WINDOW_WIDTH;
+            }
+            if h.is_some() {
+                flags |= MOVE_RESIZE_WINDOW_HEIGHT;
+            }
+
+            // Source indication in bits 12-15 identifies this as a pager request
+            flags |= 2 << 12;
+
+            // Defined as: _NET_MOVERESIZE_WINDOW, gravity and flags, x, y, width, height
+            self.send_event(ClientMessageEvent::new(
+                32,
+                id,
+                self.atoms._NET_MOVERESIZE_WINDOW,
+                [
+                    flags,
+                    x.unwrap_or(0) as u32,
+                    y.unwrap_or(0) as u32,
+                    w.unwrap_or(0),
+                    h.unwrap_or(0),
+                ],
+            ))?;
+        } else {
+            self.conn.configure_window(id, &ConfigureWindowAux::new().width(w).height(h).x(x).y(y))?;
+            self.conn.flush()?; // Requires the flush to work
+        }
+        debug!("move_resize: id: {}, g: {:?}, x: {:?}, y: {:?}, w: {:?}, h: {:?}", id, gravity, x, y, w, h);
+        Ok(())
+    }
+
+    /// Send the event ensuring that a flush is called and that the message was precisely
+    /// executed in the case of a resize/move.
+    ///
+    /// ### Arguments
+    /// * `msg` - the client message event to send
+    ///
+    /// ### Examples
+    /// ```ignore
+    /// use libwmctl::prelude::*;
+    /// let wm = WinMgr::connect().unwrap();
+    /// let flags = MOVE_RESIZE_WINDOW_WIDTH | MOVE_RESIZE_WINDOW_HEIGHT;
+    /// wm.send_event(ClientMessageEvent::new(32, win, wm.atoms._NET_MOVERESIZE_WINDOW,
+    ///     [flags, 0, 0, 500, 500])).unwrap();
+    /// ```
+    fn send_event(&self, msg: ClientMessageEvent) -> WmCtlResult<()> {
+        let mask = EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY;
+        self.conn.send_event(false, self.root, mask, msg)?.check()?;
+        self.conn.flush()?;
+        debug!("send_event: win: {}", msg.window);
+
+        // I've found that Xfwm4 does not precisely resize a window on the first request. It may be
+        // this is a function of decorating the window during a redraw. At any rate because of this
+        // unfortunate shortcoming we have to send the event a second time.
+        if msg.type_ == self.atoms._NET_MOVERESIZE_WINDOW {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            self.conn.send_event(false, self.root, mask, msg)?.check()?;
+            self.conn.flush()?;
+            debug!("send_event: win: {}", msg.window);
+        }
+        Ok(())
+    }
+
+    // Helper method to print out the data type
+    // println!("DataType NET: {:?}", AtomEnum::from(reply.type_ as u8));
+    #[allow(dead_code)]
+    fn print_data_type(reply: &GetPropertyReply) {
+        debug!("DataType: {:?}", AtomEnum::from(reply.type_ as u8));
+    }
+}
+
+/// Compute the zero based viewport cell within the large desktop that contains the given screen
+/// coordinate. Cells are numbered left to right then top to bottom.
+///
+/// ### Arguments
+/// * `viewport` - top left corner of the current viewport within the large desktop
+/// * `desktop` - large desktop (width, height)
+/// * `screen` - screen (width, height) which is the size of a single viewport cell
+/// * `x` - x coordinate relative to the screen
+/// * `y` - y coordinate relative to the screen
+fn viewport_cell(viewport: (u32, u32), desktop: (u32, u32), screen: (u32, u32), x: i32, y: i32) -> u32 {
+    let (sw, sh) = (screen.0.max(1), screen.1.max(1));
+    let cols = (desktop.0 / sw).max(1);
+    let rows = (desktop.1 / sh).max(1);
+
+    // Absolute position within the large desktop, clamped to the desktop bounds
+    let ax = (viewport.0 as i64 + x as i64).clamp(0, desktop.0.max(1) as i64 - 1) as u32;
+    let ay = (viewport.1 as i64 + y as i64).clamp(0, desktop.1.max(1) as i64 - 1) as u32;
+
+    let col = (ax / sw).min(cols - 1);
+    let row = (ay / sh).min(rows - 1);
+    row * cols + col
+}
+
+/// Compute the top left corner of the given zero based viewport cell within the large desktop.
+/// Cells are numbered left to right then top to bottom.
+///
+/// ### Arguments
+/// * `desktop` - large desktop (width, height)
+/// * `screen` - screen (width, height) which is the size of a single viewport cell
+/// * `cell` - zero based viewport cell
+///
+/// ### Returns
+/// * `(x, y)` of the viewport or None if the cell is outside the large desktop
+fn viewport_origin(desktop: (u32, u32), screen: (u32, u32), cell: u32) -> Option<(u32, u32)> {
+    let (sw, sh) = (screen.0.max(1), screen.1.max(1));
+    let cols = (desktop.0 / sw).max(1);
+    let rows = (desktop.1 / sh).max(1);
+    if cell >= cols * rows {
+        return None;
+    }
+    Some(((cell % cols) * sw, (cell / cols) * sh))
+}
+
+/// Compute the offset between the top left corners of the two given zero based viewport cells
+///
+/// ### Arguments
+/// * `desktop` - large desktop (width, height)
+/// * `screen` - screen (width, height) which is the size of a single viewport cell
+/// * `from` - zero based viewport cell to move from
+/// * `to` - zero based viewport cell to move to
+///
+/// ### Returns
+/// * `(dx, dy)` to add to a position or None if either cell is outside the large desktop
+fn viewport_delta(desktop: (u32, u32), screen: (u32, u32), from: u32, to: u32) -> Option<(i32, i32)> {
+    let (fx, fy) = viewport_origin(desktop, screen, from)?;
+    let (tx, ty) = viewport_origin(desktop, screen, to)?;
+    Some((tx as i32 - fx as i32, ty as i32 - fy as i32))
+}
+
+/// Adjust the raw geometry of a window to what is visible on screen. Window manager frame extents
+/// take precedence as a GTK app that is server side decorated may still report GTK extents.
+/// Otherwise the invisible CSD extents are removed and windows with neither are left as is.
+///
+/// ### Arguments
+/// * `geometry` - window's raw (x, y, w, h)
+/// * `borders` - window manager frame extents left, right, top, bottom
+/// * `gtk_borders` - client side decoration extents left, right, top, bottom
+///
+/// ### Returns
+/// * `(x, y, w, h)` visual geometry
+fn visual_geometry(
+    geometry: (i32, i32, u32, u32), borders: &Border, gtk_borders: &Border,
+) -> (i32, i32, u32, u32) {
+    let (x, y, w, h) = geometry;
+    if borders.any() {
+        (x - borders.l as i32, y - borders.t as i32, w + borders.w(), h + borders.h())
+    } else if gtk_borders.any() {
+        (
+            x + gtk_borders.l as i32,
+            y + gtk_borders.t as i32,
+            w.saturating_sub(gtk_borders.w()),
+            h.saturating_sub(gtk_borders.h()),
+        )
+    } else {
+        (x, y, w, h)
+    }
+}
+
+/// Split the value of a property holding a list of null terminated UTF-8 strings
+fn parse_strings(value: &[u8]) -> WmCtlResult<Vec<String>> {
+    let value = value.strip_suffix(&[0]).unwrap_or(value);
+    if value.is_empty() {
+        return Ok(vec![]);
+    }
+    value.split(|x| *x == 0).map(|x| Ok(str::from_utf8(x)?.to_owned())).collect()
+}
+
+/// Fit the published desktop names to the number of desktops defaulting missing names to `Desktop N`
+///
+/// ### Arguments
+/// * `names` - desktop names as published by the window manager
+/// * `desktops` - number of desktops
+fn pad_desktop_names(mut names: Vec<String>, desktops: u32) -> Vec<String> {
+    names.truncate(desktops as usize);
+    for i in names.len()..desktops as usize {
+        names.push(format!("Desktop {}", i + 1));
+    }
+    names
+}
+
+/// Compute the desktop the given number of steps away from the current desktop wrapping around
+/// at either end
+///
+/// ### Arguments
+/// * `current` - current desktop numbered from 1
+/// * `count` - number of desktops
+/// * `step` - number of desktops to move forward or backward if negative
+///
+/// ### Returns
+/// * desktop numbered from 1
+fn wrap_desktop(current: u32, count: u32, step: i32) -> u32 {
+    let count = count.max(1) as i64;
+    let index = (current.max(1) as i64 - 1).min(count - 1);
+    (index + step as i64).rem_euclid(count) as u32 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_desktop() {
+        assert_eq!(wrap_desktop(1, 4, 1), 2);
+        assert_eq!(wrap_desktop(4, 4, 1), 1);
+        assert_eq!(wrap_desktop(1, 4, -1), 4);
+        assert_eq!(wrap_desktop(2, 4, -1), 1);
+        assert_eq!(wrap_desktop(3, 4, 9), 4);
+        assert_eq!(wrap_desktop(3, 4, -11), 4);
+
+        // Single or missing desktops always stay on the first
+        assert_eq!(wrap_desktop(1, 1, 1), 1);
+        assert_eq!(wrap_desktop(1, 0, -1), 1);
+
+        // Out of range current desktops are clamped before stepping
+        assert_eq!(wrap_desktop(0, 4, 1), 2);
+        assert_eq!(wrap_desktop(7, 4, 1), 1);
+    }
+
+    #[test]
+    fn test_viewport_cell() {
+        let screen = (1920, 1080);
+
+        // Single row of four viewports
+        let desktop = (1920 * 4, 1080);
+        assert_eq!(viewport_cell((0, 0), desktop, screen, 100, 100), 0);
+        assert_eq!(viewport_cell((0, 0), desktop, screen, 2000, 100), 1);
+        assert_eq!(viewport_cell((1920, 0), desktop, screen, 100, 100), 1);
+        assert_eq!(viewport_cell((1920 * 3, 0), desktop, screen, 1919, 1079), 3);
+
+        // Off screen coordinates are clamped to the desktop
+        assert_eq!(viewport_cell((0, 0), desktop, screen, -50, -50), 0);
+        assert_eq!(viewport_cell((1920 * 3, 0), desktop, screen, 5000, 100), 3);
+        assert_eq!(viewport_cell((1920, 0), desktop, screen, -100, 100), 0);
+
+        // Two by two grid of viewports
+        let desktop = (1920 * 2, 1080 * 2);
+        assert_eq!(viewport_cell((0, 0), desktop, screen, 100, 1200), 2);
+        assert_eq!(viewport_cell((1920, 1080), desktop, screen, 100, 100), 3);
+        assert_eq!(viewport_cell((1920, 1080), desktop, screen, -100, 100), 2);
+    }
+
+    #[test]
+    fn test_viewport_delta() {
+        let screen = (1920, 1080);
+        let desktop = (1920 * 2, 1080 * 2);
+        assert_eq!(viewport_delta(desktop, screen, 0, 3), Some((1920, 1080)));
+        assert_eq!(viewport_delta(desktop, screen, 3, 2), Some((-1920, 0)));
+        assert_eq!(viewport_delta(desktop, screen, 1, 1), Some((0, 0)));
+        assert_eq!(viewport_delta(desktop, screen, 0, 4), None);
+    }
+
+    #[test]
+    fn test_visual_geometry() {
+        let geometry = (100, 100, 800, 600);
+        let borders = Border::new(4, 4, 28, 4);
+        let gtk = Border::new(23, 23, 15, 31);
+        let none = Border::default();
+
+        // Undecorated windows are left as is
+        assert_eq!(visual_geometry(geometry, &none, &none), geometry);
+
+        // Window manager frames are added on
+        assert_eq!(visual_geometry(geometry, &borders, &none), (96, 72, 808, 632));
+
+        // Invisible CSD extents are removed
+        assert_eq!(visual_geometry(geometry, &none, &gtk), (123, 115, 754, 554));
+
+        // Server side decorated GTK apps only count the frame
+        assert_eq!(visual_geometry(geometry, &borders, &gtk), (96, 72, 808, 632));
+    }
+
+    #[test]
+    fn test_pad_desktop_names() {
+        let names = parse_strings(b"one\0\0three\0").unwrap();
+        assert_eq!(pad_desktop_names(names.clone(), 5), vec!["one", "", "three", "Desktop 4", "Desktop 5"]);
+        assert_eq!(pad_desktop_names(names.clone(), 2), vec!["one", ""]);
+        assert_eq!(pad_desktop_names(vec![], 1), vec!["Desktop 1"]);
+        assert_eq!(pad_desktop_names(names, 0), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_parse_strings() {
+        assert_eq!(parse_strings(b"one\0two\0").unwrap(), vec!["one", "two"]);
+        assert_eq!(parse_strings(b"one\0\0three").unwrap(), vec!["one", "", "three"]);
+        assert_eq!(parse_strings(b"").unwrap(), Vec::<String>::new());
+        assert!(parse_strings(&[0xff, 0]).is_err());
+    }
+
+    #[test]
+    fn test_viewport_origin() {
+        let screen = (1920, 1080);
+
+        // Single row of four viewports
+        let desktop = (1920 * 4, 1080);
+        assert_eq!(viewport_origin(desktop, screen, 0), Some((0, 0)));
+        assert_eq!(viewport_origin(desktop, screen, 3), Some((1920 * 3, 0)));
+        assert_eq!(viewport_origin(desktop, screen, 4), None);
+
+        // Two by two grid of viewports
+        let desktop = (1920 * 2, 1080 * 2);
+        assert_eq!(viewport_origin(desktop, screen, 2), Some((0, 1080)));
+        assert_eq!(viewport_origin(desktop, screen, 3), Some((1920, 1080)));
+        for cell in 0..4 {
+            let (x, y) = viewport_origin(desktop, screen, cell).unwrap();
+            assert_eq!(viewport_cell((x, y), desktop, screen, 0, 0), cell);
+        }
     }
 }
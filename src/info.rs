@@ -49,7 +49,7 @@ pub fn winmgr(all: bool) {
         let mut atoms = wm.supported.iter().collect::<Vec<_>>();
         atoms.sort_by(|a, b| a.1.cmp(b.1));
         for atom in atoms.iter() {
-            table.add_row(Row::new(vec![Cell::new(&atom.1), Cell::new(&atom.0.to_string())]));
+            table.add_row(Row::new(vec![Cell::new(atom.1), Cell::new(&atom.0.to_string())]));
         }
         table.printstd();
     }
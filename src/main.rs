@@ -30,7 +30,6 @@ use std::env;
 use clap::{App, AppSettings, Arg, SubCommand};
 use gory::*;
 use tracing::Level;
-use tracing_subscriber;
 use witcher::prelude::*;
 
 mod info;
@@ -208,7 +207,7 @@ wmctl static 1276 757 0 0
     });
 
     // Version
-    if let Some(ref _matches) = matches.subcommand_matches("version") {
+    if let Some(_matches) = matches.subcommand_matches("version") {
         println!("{}: {}", APP_NAME.cyan(), APP_DESCRIPTION.cyan());
         println!("{}", "--------------------------------------------------------".cyan());
         println!("{:<w$} {}", "Version:", APP_VERSION, w = 18);
@@ -22,7 +22,7 @@ pub fn get_window_id(matches: &ArgMatches, active: bool) -> u32 {
         }
         id
     } else if matches.is_present("class") {
-        let id = matches.value_of("class").and_then(|x| libwmctl::first_by_class(x).and_then(|x| Some(x.id)));
+        let id = matches.value_of("class").and_then(|x| libwmctl::first_by_class(x).map(|x| x.id));
         if id.is_none() {
             fatal(&format!("Not found Window class: {}", matches.value_of("class").unwrap()));
         }